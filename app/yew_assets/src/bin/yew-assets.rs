@@ -0,0 +1,268 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use yew_assets::{Favicon, Icon, IconCategory, IconFont};
+
+const USAGE: &str = "Usage:
+    yew-assets list [--category <categories>]
+    yew-assets search <term> [--category <categories>]
+    yew-assets show <name> [--size <size>] [--stroke <color>]
+    yew-assets export --out <dir> [--category <categories>] [--size <size>] [--stroke <color>]
+    yew-assets font --out <dir> [--category <categories>] [--family <name>]
+    yew-assets favicon <name> --out <dir> [--stroke <color>] [--background <color>] [--padding <fraction>] [--radius <fraction>]
+
+The categories are the features without the _assets suffix separated by commas, like ux,dev";
+
+struct Options {
+    args: Vec<String>,
+    category: Option<String>,
+    out: Option<String>,
+    size: String,
+    stroke: String,
+    background: Option<String>,
+    padding: Option<String>,
+    radius: Option<String>,
+    family: Option<String>,
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    let command = match args.next() {
+        Some(command) => command,
+        None => exit_with_usage(),
+    };
+
+    let options = get_options(args.collect());
+
+    let result = match command.as_str() {
+        "list" => list(&options),
+        "search" => search(&options),
+        "show" => show(&options),
+        "export" => export(&options),
+        "font" => font(&options),
+        "favicon" => favicon(&options),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => exit_with_usage(),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn get_options(args: Vec<String>) -> Options {
+    let mut options = Options {
+        args: vec![],
+        category: None,
+        out: None,
+        size: "24".to_string(),
+        stroke: "currentColor".to_string(),
+        background: None,
+        padding: None,
+        radius: None,
+        family: None,
+    };
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--category" => options.category = Some(get_value(&arg, args.next())),
+            "--out" => options.out = Some(get_value(&arg, args.next())),
+            "--size" => options.size = get_value(&arg, args.next()),
+            "--stroke" => options.stroke = get_value(&arg, args.next()),
+            "--background" => options.background = Some(get_value(&arg, args.next())),
+            "--padding" => options.padding = Some(get_value(&arg, args.next())),
+            "--radius" => options.radius = Some(get_value(&arg, args.next())),
+            "--family" => options.family = Some(get_value(&arg, args.next())),
+            _ => options.args.push(arg),
+        }
+    }
+
+    options
+}
+
+fn get_value(flag: &str, value: Option<String>) -> String {
+    match value {
+        Some(value) => value,
+        None => {
+            eprintln!("error: {} requires a value", flag);
+            exit_with_usage()
+        }
+    }
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2)
+}
+
+fn get_icons(options: &Options) -> Result<Vec<Icon>, String> {
+    let categories = match &options.category {
+        Some(categories) => categories
+            .split(',')
+            .map(|category| {
+                IconCategory::from_feature(category)
+                    .ok_or_else(|| format!("category {} not found", category.trim()))
+            })
+            .collect::<Result<Vec<IconCategory>, String>>()?,
+        None => IconCategory::ALL.to_vec(),
+    };
+
+    Ok(categories
+        .iter()
+        .flat_map(|category| category.icons())
+        .collect())
+}
+
+fn list(options: &Options) -> Result<(), String> {
+    for icon in get_icons(options)? {
+        println!("{}\t{}", icon.category().short_name(), icon.name());
+    }
+
+    Ok(())
+}
+
+fn search(options: &Options) -> Result<(), String> {
+    let term = match options.args.first() {
+        Some(term) => term.to_lowercase(),
+        None => return Err("search requires a term".to_string()),
+    };
+
+    for icon in get_icons(options)?
+        .into_iter()
+        .filter(|icon| icon.name().contains(&term))
+    {
+        println!("{}\t{}", icon.category().short_name(), icon.name());
+    }
+
+    Ok(())
+}
+
+fn show(options: &Options) -> Result<(), String> {
+    let name = match options.args.first() {
+        Some(name) => name,
+        None => return Err("show requires the name of an icon".to_string()),
+    };
+
+    match Icon::from_name(name) {
+        Some(icon) => {
+            println!("{}", icon.to_svg(&options.size, &options.stroke));
+            Ok(())
+        }
+        None => Err(format!("icon {} not found", name)),
+    }
+}
+
+fn export(options: &Options) -> Result<(), String> {
+    let out = match &options.out {
+        Some(out) => PathBuf::from(out),
+        None => return Err("export requires --out <dir>".to_string()),
+    };
+
+    fs::create_dir_all(&out).map_err(|e| format!("{}: {}", out.display(), e))?;
+
+    let icons = get_icons(options)?;
+
+    for icon in icons.iter() {
+        let path = out.join(format!("{}.svg", icon.name()));
+        fs::write(&path, icon.to_svg(&options.size, &options.stroke))
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    println!("{} icons exported to {}", icons.len(), out.display());
+
+    Ok(())
+}
+
+fn font(options: &Options) -> Result<(), String> {
+    let out = match &options.out {
+        Some(out) => PathBuf::from(out),
+        None => return Err("font requires --out <dir>".to_string()),
+    };
+
+    let mut font = IconFont::new(get_icons(options)?);
+
+    if let Some(family) = &options.family {
+        font.family = family.clone();
+    }
+
+    let font_name = format!("{}.woff2", font.family.replace(' ', "-").to_lowercase());
+    let css_name = format!("{}.css", font.family.replace(' ', "-").to_lowercase());
+
+    fs::create_dir_all(&out).map_err(|e| format!("{}: {}", out.display(), e))?;
+
+    for (name, content) in [
+        (&font_name, font.to_woff2()),
+        (&css_name, font.to_css(&font_name).into_bytes()),
+    ]
+    .iter()
+    {
+        let path = out.join(name);
+        fs::write(&path, content).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    println!(
+        "{} icons compiled to {} and {} in {}",
+        font.icons.len(),
+        font_name,
+        css_name,
+        out.display()
+    );
+
+    Ok(())
+}
+
+fn favicon(options: &Options) -> Result<(), String> {
+    let name = match options.args.first() {
+        Some(name) => name,
+        None => return Err("favicon requires the name of an icon".to_string()),
+    };
+
+    let out = match &options.out {
+        Some(out) => PathBuf::from(out),
+        None => return Err("favicon requires --out <dir>".to_string()),
+    };
+
+    let mut favicon = match Icon::from_name(name) {
+        Some(icon) => Favicon::new(icon),
+        None => return Err(format!("icon {} not found", name)),
+    };
+
+    if options.stroke != "currentColor" {
+        favicon.foreground = options.stroke.clone();
+    }
+
+    if let Some(background) = &options.background {
+        favicon.background = background.clone();
+    }
+
+    if let Some(padding) = &options.padding {
+        favicon.padding = get_fraction("--padding", padding)?;
+    }
+
+    if let Some(radius) = &options.radius {
+        favicon.corner_radius = get_fraction("--radius", radius)?;
+    }
+
+    favicon
+        .write(&out)
+        .map_err(|e| format!("{}: {}", out.display(), e))?;
+
+    println!("{}", favicon.manifest_icons("/"));
+
+    Ok(())
+}
+
+fn get_fraction(flag: &str, value: &str) -> Result<f32, String> {
+    match value.parse::<f32>() {
+        Ok(fraction) if (0.0..=0.5).contains(&fraction) => Ok(fraction),
+        _ => Err(format!("{} must be a number between 0 and 0.5", flag)),
+    }
+}
@@ -0,0 +1,268 @@
+use crate::Icon;
+
+#[cfg(feature = "dev_assets")]
+use crate::dev_assets::DevIcon;
+#[cfg(feature = "social_assets")]
+use crate::social_assets::SocialIcon;
+
+/// # Brand
+///
+/// Brands of the social and dev icons with their official color, homepage and share url
+///
+/// ## Feature
+/// social_assets or dev_assets
+///
+/// ## Example
+/// ```rust
+/// use yew_assets::Brand;
+///
+/// let share_url = Brand::Twitter.share_url("https://yew.rs", "Yew & assets");
+///
+/// assert_eq!(Brand::Twitter.color(), "#1DA1F2");
+/// assert_eq!(
+///     share_url.unwrap(),
+///     "https://twitter.com/intent/tweet?url=https%3A%2F%2Fyew.rs&text=Yew%20%26%20assets"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Brand {
+    #[cfg(feature = "social_assets")]
+    Facebook,
+    #[cfg(feature = "social_assets")]
+    Twitter,
+    #[cfg(feature = "social_assets")]
+    Instagram,
+    #[cfg(feature = "social_assets")]
+    Linkedin,
+    #[cfg(feature = "social_assets")]
+    Twitch,
+    #[cfg(feature = "social_assets")]
+    Slack,
+    #[cfg(feature = "dev_assets")]
+    Github,
+    #[cfg(feature = "dev_assets")]
+    Gitlab,
+    #[cfg(feature = "dev_assets")]
+    Codepen,
+    #[cfg(feature = "dev_assets")]
+    Codesandbox,
+    #[cfg(feature = "dev_assets")]
+    Figma,
+    #[cfg(feature = "dev_assets")]
+    Framer,
+    #[cfg(feature = "dev_assets")]
+    Trello,
+}
+
+impl Brand {
+    /// All the brands of the enabled features
+    pub const ALL: &'static [Brand] = &[
+        #[cfg(feature = "social_assets")]
+        Brand::Facebook,
+        #[cfg(feature = "social_assets")]
+        Brand::Twitter,
+        #[cfg(feature = "social_assets")]
+        Brand::Instagram,
+        #[cfg(feature = "social_assets")]
+        Brand::Linkedin,
+        #[cfg(feature = "social_assets")]
+        Brand::Twitch,
+        #[cfg(feature = "social_assets")]
+        Brand::Slack,
+        #[cfg(feature = "dev_assets")]
+        Brand::Github,
+        #[cfg(feature = "dev_assets")]
+        Brand::Gitlab,
+        #[cfg(feature = "dev_assets")]
+        Brand::Codepen,
+        #[cfg(feature = "dev_assets")]
+        Brand::Codesandbox,
+        #[cfg(feature = "dev_assets")]
+        Brand::Figma,
+        #[cfg(feature = "dev_assets")]
+        Brand::Framer,
+        #[cfg(feature = "dev_assets")]
+        Brand::Trello,
+    ];
+
+    /// Find the brand of an icon
+    pub fn from_icon(icon: &Icon) -> Option<Brand> {
+        Brand::ALL
+            .iter()
+            .find(|brand| brand.icon().name() == icon.name())
+            .copied()
+    }
+
+    /// Name of the brand
+    pub fn name(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "social_assets")]
+            Brand::Facebook => "Facebook",
+            #[cfg(feature = "social_assets")]
+            Brand::Twitter => "Twitter",
+            #[cfg(feature = "social_assets")]
+            Brand::Instagram => "Instagram",
+            #[cfg(feature = "social_assets")]
+            Brand::Linkedin => "LinkedIn",
+            #[cfg(feature = "social_assets")]
+            Brand::Twitch => "Twitch",
+            #[cfg(feature = "social_assets")]
+            Brand::Slack => "Slack",
+            #[cfg(feature = "dev_assets")]
+            Brand::Github => "GitHub",
+            #[cfg(feature = "dev_assets")]
+            Brand::Gitlab => "GitLab",
+            #[cfg(feature = "dev_assets")]
+            Brand::Codepen => "CodePen",
+            #[cfg(feature = "dev_assets")]
+            Brand::Codesandbox => "CodeSandbox",
+            #[cfg(feature = "dev_assets")]
+            Brand::Figma => "Figma",
+            #[cfg(feature = "dev_assets")]
+            Brand::Framer => "Framer",
+            #[cfg(feature = "dev_assets")]
+            Brand::Trello => "Trello",
+        }
+    }
+
+    /// Official color of the brand
+    pub fn color(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "social_assets")]
+            Brand::Facebook => "#1877F2",
+            #[cfg(feature = "social_assets")]
+            Brand::Twitter => "#1DA1F2",
+            #[cfg(feature = "social_assets")]
+            Brand::Instagram => "#E4405F",
+            #[cfg(feature = "social_assets")]
+            Brand::Linkedin => "#0A66C2",
+            #[cfg(feature = "social_assets")]
+            Brand::Twitch => "#9146FF",
+            #[cfg(feature = "social_assets")]
+            Brand::Slack => "#4A154B",
+            #[cfg(feature = "dev_assets")]
+            Brand::Github => "#181717",
+            #[cfg(feature = "dev_assets")]
+            Brand::Gitlab => "#FC6D26",
+            #[cfg(feature = "dev_assets")]
+            Brand::Codepen => "#000000",
+            #[cfg(feature = "dev_assets")]
+            Brand::Codesandbox => "#151515",
+            #[cfg(feature = "dev_assets")]
+            Brand::Figma => "#F24E1E",
+            #[cfg(feature = "dev_assets")]
+            Brand::Framer => "#0055FF",
+            #[cfg(feature = "dev_assets")]
+            Brand::Trello => "#0052CC",
+        }
+    }
+
+    /// Homepage of the brand
+    pub fn homepage(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "social_assets")]
+            Brand::Facebook => "https://www.facebook.com",
+            #[cfg(feature = "social_assets")]
+            Brand::Twitter => "https://twitter.com",
+            #[cfg(feature = "social_assets")]
+            Brand::Instagram => "https://www.instagram.com",
+            #[cfg(feature = "social_assets")]
+            Brand::Linkedin => "https://www.linkedin.com",
+            #[cfg(feature = "social_assets")]
+            Brand::Twitch => "https://www.twitch.tv",
+            #[cfg(feature = "social_assets")]
+            Brand::Slack => "https://slack.com",
+            #[cfg(feature = "dev_assets")]
+            Brand::Github => "https://github.com",
+            #[cfg(feature = "dev_assets")]
+            Brand::Gitlab => "https://gitlab.com",
+            #[cfg(feature = "dev_assets")]
+            Brand::Codepen => "https://codepen.io",
+            #[cfg(feature = "dev_assets")]
+            Brand::Codesandbox => "https://codesandbox.io",
+            #[cfg(feature = "dev_assets")]
+            Brand::Figma => "https://www.figma.com",
+            #[cfg(feature = "dev_assets")]
+            Brand::Framer => "https://www.framer.com",
+            #[cfg(feature = "dev_assets")]
+            Brand::Trello => "https://trello.com",
+        }
+    }
+
+    /// Template of the url to share a page, with the `{url}` and `{text}` placeholders.
+    /// The brands which can't share a page from an url don't have it
+    pub fn share_url_template(&self) -> Option<&'static str> {
+        match self {
+            #[cfg(feature = "social_assets")]
+            Brand::Facebook => Some("https://www.facebook.com/sharer/sharer.php?u={url}"),
+            #[cfg(feature = "social_assets")]
+            Brand::Twitter => Some("https://twitter.com/intent/tweet?url={url}&text={text}"),
+            #[cfg(feature = "social_assets")]
+            Brand::Linkedin => Some("https://www.linkedin.com/sharing/share-offsite/?url={url}"),
+            _ => None,
+        }
+    }
+
+    /// Url to share a page with a text, both of them encoded
+    pub fn share_url(&self, url: &str, text: &str) -> Option<String> {
+        self.share_url_template().map(|template| {
+            template
+                .replace("{url}", &encode_uri_component(url))
+                .replace("{text}", &encode_uri_component(text))
+        })
+    }
+
+    /// Icon of the brand
+    pub fn icon(&self) -> Icon {
+        match self {
+            #[cfg(feature = "social_assets")]
+            Brand::Facebook => SocialIcon::Facebook.into(),
+            #[cfg(feature = "social_assets")]
+            Brand::Twitter => SocialIcon::Twitter.into(),
+            #[cfg(feature = "social_assets")]
+            Brand::Instagram => SocialIcon::Instagram.into(),
+            #[cfg(feature = "social_assets")]
+            Brand::Linkedin => SocialIcon::Linkedin.into(),
+            #[cfg(feature = "social_assets")]
+            Brand::Twitch => SocialIcon::Twitch.into(),
+            #[cfg(feature = "social_assets")]
+            Brand::Slack => SocialIcon::Slack.into(),
+            #[cfg(feature = "dev_assets")]
+            Brand::Github => DevIcon::Github.into(),
+            #[cfg(feature = "dev_assets")]
+            Brand::Gitlab => DevIcon::Gitlab.into(),
+            #[cfg(feature = "dev_assets")]
+            Brand::Codepen => DevIcon::Codepen.into(),
+            #[cfg(feature = "dev_assets")]
+            Brand::Codesandbox => DevIcon::Codesandbox.into(),
+            #[cfg(feature = "dev_assets")]
+            Brand::Figma => DevIcon::Figma.into(),
+            #[cfg(feature = "dev_assets")]
+            Brand::Framer => DevIcon::Framer.into(),
+            #[cfg(feature = "dev_assets")]
+            Brand::Trello => DevIcon::Trello.into(),
+        }
+    }
+}
+
+/// Percent-encode everything but the unreserved characters, like `encodeURIComponent`
+fn encode_uri_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'!'
+            | b'~'
+            | b'*'
+            | b'\''
+            | b'('
+            | b')' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
@@ -0,0 +1,293 @@
+use crate::Icon;
+use std::fmt;
+
+#[cfg(feature = "browser_assets")]
+use crate::browser_assets::BrowserIcon;
+#[cfg(feature = "business_assets")]
+use crate::business_assets::BusinessIcon;
+#[cfg(feature = "communication_assets")]
+use crate::communication_assets::CommunicationIcon;
+#[cfg(feature = "controller_assets")]
+use crate::controller_assets::ControllerIcon;
+#[cfg(feature = "dev_assets")]
+use crate::dev_assets::DevIcon;
+#[cfg(feature = "device_assets")]
+use crate::device_assets::DeviceIcon;
+#[cfg(feature = "editing_assets")]
+use crate::editing_assets::EditingIcon;
+#[cfg(feature = "env_assets")]
+use crate::env_assets::EnvIcon;
+#[cfg(feature = "file_assets")]
+use crate::file_assets::FileIcon;
+#[cfg(feature = "info_assets")]
+use crate::info_assets::InfoIcon;
+#[cfg(feature = "multimedia_assets")]
+use crate::multimedia_assets::MultimediaIcon;
+#[cfg(feature = "nav_assets")]
+use crate::nav_assets::NavIcon;
+#[cfg(feature = "object_assets")]
+use crate::object_assets::ObjectIcon;
+#[cfg(feature = "social_assets")]
+use crate::social_assets::SocialIcon;
+#[cfg(feature = "ux_assets")]
+use crate::ux_assets::UxIcon;
+
+/// # IconCategory
+///
+/// Category of the icons, one for every `*_assets` feature.
+/// Only the categories of the enabled features are included
+///
+/// ## Example
+/// ```rust
+/// use yew_assets::IconCategory;
+///
+/// for category in IconCategory::ALL {
+///     println!("{}: {} icons", category.display_name(), category.icons().len());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IconCategory {
+    #[cfg(feature = "browser_assets")]
+    Browser,
+    #[cfg(feature = "business_assets")]
+    Business,
+    #[cfg(feature = "communication_assets")]
+    Communication,
+    #[cfg(feature = "controller_assets")]
+    Controller,
+    #[cfg(feature = "dev_assets")]
+    Dev,
+    #[cfg(feature = "device_assets")]
+    Device,
+    #[cfg(feature = "editing_assets")]
+    Editing,
+    #[cfg(feature = "env_assets")]
+    Env,
+    #[cfg(feature = "file_assets")]
+    File,
+    #[cfg(feature = "info_assets")]
+    Info,
+    #[cfg(feature = "multimedia_assets")]
+    Multimedia,
+    #[cfg(feature = "nav_assets")]
+    Nav,
+    #[cfg(feature = "object_assets")]
+    Object,
+    #[cfg(feature = "social_assets")]
+    Social,
+    #[cfg(feature = "ux_assets")]
+    Ux,
+}
+
+impl IconCategory {
+    /// All the categories of the enabled features
+    pub const ALL: &'static [IconCategory] = &[
+        #[cfg(feature = "browser_assets")]
+        IconCategory::Browser,
+        #[cfg(feature = "business_assets")]
+        IconCategory::Business,
+        #[cfg(feature = "communication_assets")]
+        IconCategory::Communication,
+        #[cfg(feature = "controller_assets")]
+        IconCategory::Controller,
+        #[cfg(feature = "dev_assets")]
+        IconCategory::Dev,
+        #[cfg(feature = "device_assets")]
+        IconCategory::Device,
+        #[cfg(feature = "editing_assets")]
+        IconCategory::Editing,
+        #[cfg(feature = "env_assets")]
+        IconCategory::Env,
+        #[cfg(feature = "file_assets")]
+        IconCategory::File,
+        #[cfg(feature = "info_assets")]
+        IconCategory::Info,
+        #[cfg(feature = "multimedia_assets")]
+        IconCategory::Multimedia,
+        #[cfg(feature = "nav_assets")]
+        IconCategory::Nav,
+        #[cfg(feature = "object_assets")]
+        IconCategory::Object,
+        #[cfg(feature = "social_assets")]
+        IconCategory::Social,
+        #[cfg(feature = "ux_assets")]
+        IconCategory::Ux,
+    ];
+
+    /// Find a category by its feature, with or without the `_assets` suffix
+    pub fn from_feature(feature: &str) -> Option<IconCategory> {
+        let feature = feature.trim().trim_end_matches("_assets");
+
+        IconCategory::ALL
+            .iter()
+            .find(|category| category.short_name() == feature)
+            .copied()
+    }
+
+    /// Cargo feature which enables the category, like `ux_assets`
+    pub fn feature(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "browser_assets")]
+            IconCategory::Browser => "browser_assets",
+            #[cfg(feature = "business_assets")]
+            IconCategory::Business => "business_assets",
+            #[cfg(feature = "communication_assets")]
+            IconCategory::Communication => "communication_assets",
+            #[cfg(feature = "controller_assets")]
+            IconCategory::Controller => "controller_assets",
+            #[cfg(feature = "dev_assets")]
+            IconCategory::Dev => "dev_assets",
+            #[cfg(feature = "device_assets")]
+            IconCategory::Device => "device_assets",
+            #[cfg(feature = "editing_assets")]
+            IconCategory::Editing => "editing_assets",
+            #[cfg(feature = "env_assets")]
+            IconCategory::Env => "env_assets",
+            #[cfg(feature = "file_assets")]
+            IconCategory::File => "file_assets",
+            #[cfg(feature = "info_assets")]
+            IconCategory::Info => "info_assets",
+            #[cfg(feature = "multimedia_assets")]
+            IconCategory::Multimedia => "multimedia_assets",
+            #[cfg(feature = "nav_assets")]
+            IconCategory::Nav => "nav_assets",
+            #[cfg(feature = "object_assets")]
+            IconCategory::Object => "object_assets",
+            #[cfg(feature = "social_assets")]
+            IconCategory::Social => "social_assets",
+            #[cfg(feature = "ux_assets")]
+            IconCategory::Ux => "ux_assets",
+        }
+    }
+
+    /// Name of the feature without the `_assets` suffix, like `ux`
+    pub fn short_name(&self) -> &'static str {
+        self.feature().trim_end_matches("_assets")
+    }
+
+    /// Name of the category to show to the users
+    pub fn display_name(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "browser_assets")]
+            IconCategory::Browser => "Browser",
+            #[cfg(feature = "business_assets")]
+            IconCategory::Business => "Business",
+            #[cfg(feature = "communication_assets")]
+            IconCategory::Communication => "Communication",
+            #[cfg(feature = "controller_assets")]
+            IconCategory::Controller => "Controller",
+            #[cfg(feature = "dev_assets")]
+            IconCategory::Dev => "Development",
+            #[cfg(feature = "device_assets")]
+            IconCategory::Device => "Device",
+            #[cfg(feature = "editing_assets")]
+            IconCategory::Editing => "Editing",
+            #[cfg(feature = "env_assets")]
+            IconCategory::Env => "Environment",
+            #[cfg(feature = "file_assets")]
+            IconCategory::File => "File",
+            #[cfg(feature = "info_assets")]
+            IconCategory::Info => "Information",
+            #[cfg(feature = "multimedia_assets")]
+            IconCategory::Multimedia => "Multimedia",
+            #[cfg(feature = "nav_assets")]
+            IconCategory::Nav => "Navigation",
+            #[cfg(feature = "object_assets")]
+            IconCategory::Object => "Object",
+            #[cfg(feature = "social_assets")]
+            IconCategory::Social => "Social",
+            #[cfg(feature = "ux_assets")]
+            IconCategory::Ux => "User experience",
+        }
+    }
+
+    /// Short description of the icons of the category
+    pub fn description(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "browser_assets")]
+            IconCategory::Browser => "Web browsers and web navigation",
+            #[cfg(feature = "business_assets")]
+            IconCategory::Business => "Charts, trends, money and payments",
+            #[cfg(feature = "communication_assets")]
+            IconCategory::Communication => "Messages, mail and phone calls",
+            #[cfg(feature = "controller_assets")]
+            IconCategory::Controller => "Arrows, chevrons and playback controls",
+            #[cfg(feature = "dev_assets")]
+            IconCategory::Dev => "Code, version control, terminals and servers",
+            #[cfg(feature = "device_assets")]
+            IconCategory::Device => "Computers, phones, peripherals and hardware",
+            #[cfg(feature = "editing_assets")]
+            IconCategory::Editing => "Text formatting, drawing and editing tools",
+            #[cfg(feature = "env_assets")]
+            IconCategory::Env => "Weather, nature and the environment",
+            #[cfg(feature = "file_assets")]
+            IconCategory::File => "Files, folders and documents",
+            #[cfg(feature = "info_assets")]
+            IconCategory::Info => "Alerts, help and information",
+            #[cfg(feature = "multimedia_assets")]
+            IconCategory::Multimedia => "Images, music, video and sound",
+            #[cfg(feature = "nav_assets")]
+            IconCategory::Nav => "Maps, locations and navigation",
+            #[cfg(feature = "object_assets")]
+            IconCategory::Object => "Everyday objects",
+            #[cfg(feature = "social_assets")]
+            IconCategory::Social => "Social networks and brands",
+            #[cfg(feature = "ux_assets")]
+            IconCategory::Ux => "User interface controls and actions",
+        }
+    }
+
+    /// List of all the icons of the category
+    pub fn icons(&self) -> Vec<Icon> {
+        match *self {
+            #[cfg(feature = "browser_assets")]
+            IconCategory::Browser => BrowserIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "business_assets")]
+            IconCategory::Business => BusinessIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "communication_assets")]
+            IconCategory::Communication => CommunicationIcon::ALL
+                .iter()
+                .cloned()
+                .map(Icon::from)
+                .collect(),
+            #[cfg(feature = "controller_assets")]
+            IconCategory::Controller => ControllerIcon::ALL
+                .iter()
+                .cloned()
+                .map(Icon::from)
+                .collect(),
+            #[cfg(feature = "dev_assets")]
+            IconCategory::Dev => DevIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "device_assets")]
+            IconCategory::Device => DeviceIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "editing_assets")]
+            IconCategory::Editing => EditingIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "env_assets")]
+            IconCategory::Env => EnvIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "file_assets")]
+            IconCategory::File => FileIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "info_assets")]
+            IconCategory::Info => InfoIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "multimedia_assets")]
+            IconCategory::Multimedia => MultimediaIcon::ALL
+                .iter()
+                .cloned()
+                .map(Icon::from)
+                .collect(),
+            #[cfg(feature = "nav_assets")]
+            IconCategory::Nav => NavIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "object_assets")]
+            IconCategory::Object => ObjectIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "social_assets")]
+            IconCategory::Social => SocialIcon::ALL.iter().cloned().map(Icon::from).collect(),
+            #[cfg(feature = "ux_assets")]
+            IconCategory::Ux => UxIcon::ALL.iter().cloned().map(Icon::from).collect(),
+        }
+    }
+}
+
+impl fmt::Display for IconCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
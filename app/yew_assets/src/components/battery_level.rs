@@ -0,0 +1,166 @@
+use crate::device_assets::{get_icon, DeviceIcon};
+use yew::prelude::*;
+use yew::virtual_dom::VNode;
+
+pub enum Msg {}
+
+/// # BatteryLevel
+///
+/// `DeviceIcon::Battery` with a bar filled proportionally to the level,
+/// which changes its color at the levels of `colors` and reads the level
+/// to the screen readers, like "42 percent"
+///
+/// ## Feature
+/// device_assets
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_assets::battery_level::BatteryLevel;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App {}
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         <BatteryLevel
+///             percent = 42
+///             charging = true
+///             size = ("48".to_string(),"48".to_string())
+///         />
+///     }
+/// }
+/// ```
+pub struct BatteryLevel {
+    pub props: Props,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    /// Level of the battery, from 0 to 100
+    pub percent: u32,
+    /// Show a bolt over the level
+    #[prop_or_default]
+    pub charging: bool,
+    /// Color of the level bar by the highest level of every color, in ascending order.
+    /// The level takes the first color whose level is greater or equal
+    #[prop_or(vec![
+        (20, "#e53935".to_string()),
+        (50, "#fb8c00".to_string()),
+        (100, "#43a047".to_string()),
+    ])]
+    pub colors: Vec<(u32, String)>,
+    /// Label read by the screen readers. By default the level, like "42 percent"
+    #[prop_or_default]
+    pub label: Option<String>,
+    /// Size of the BatteryLevel
+    #[prop_or(("24".to_string(),"24".to_string()))]
+    pub size: (String, String),
+    /// Fill the color of the asset
+    #[prop_or("none".to_string())]
+    pub fill: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+}
+
+impl Component for BatteryLevel {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let percent = self.props.percent.min(100);
+        let label = match &self.props.label {
+            Some(label) => label.clone(),
+            None if self.props.charging => format!("{} percent, charging", percent),
+            None => format!("{} percent", percent),
+        };
+        let icon = get_icon(
+            DeviceIcon::Battery,
+            self.props.size.clone(),
+            (
+                "0".to_string(),
+                "0".to_string(),
+                "24".to_string(),
+                "24".to_string(),
+            ),
+            self.props.fill.clone(),
+            self.props.class_name.clone(),
+            self.props.id.clone(),
+        );
+
+        match icon {
+            VNode::VTag(mut tag) => {
+                tag.add_attribute("role", "img");
+                tag.add_attribute("aria-label", label);
+                tag.children.push(self.get_level(percent));
+
+                if self.props.charging {
+                    tag.children.push(html! {
+                        <polyline points="11 8 8 12 12 12 9 16" stroke-width="1.5"></polyline>
+                    });
+                }
+
+                VNode::VTag(tag)
+            }
+            icon => icon,
+        }
+    }
+}
+
+impl BatteryLevel {
+    /// Bar inside the outline of the battery with the width of the level
+    fn get_level(&self, percent: u32) -> Html {
+        let color = self
+            .props
+            .colors
+            .iter()
+            .find(|(level, _)| percent <= *level)
+            .or_else(|| self.props.colors.last())
+            .map_or("currentColor", |(_, color)| color.as_str());
+        // the inside of the outline goes from 3 to 17 leaving a gap of 1 with the stroke
+        let width = 14.0 * percent as f32 / 100.0;
+
+        html! {
+            <rect
+                x="3"
+                y="8"
+                width=width.to_string()
+                height="8"
+                rx="0.5"
+                fill=color.to_string()
+                stroke="none"
+            ></rect>
+        }
+    }
+}
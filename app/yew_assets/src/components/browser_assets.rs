@@ -1,6 +1,11 @@
+use crate::direction::{get_directional_icon, is_rtl, LayoutDirection};
+use crate::variant::{get_variant_icon, IconVariant};
+use yew::agent::{Bridge, Bridged};
 use yew::prelude::*;
 
-pub enum Msg {}
+pub enum Msg {
+    Direction(bool),
+}
 
 /// # BrowserAssets
 ///
@@ -43,6 +48,8 @@ pub enum Msg {}
 /// ```
 pub struct BrowserAssets {
     pub props: Props,
+    rtl: bool,
+    _direction: Box<dyn Bridge<LayoutDirection>>,
 }
 
 #[derive(Clone, Properties)]
@@ -64,18 +71,40 @@ pub struct Props {
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
+    /// Mirror the BrowserIcon if it is direction-sensitive. By default it follows the `LayoutDirection` context
+    #[prop_or_default]
+    pub rtl: Option<bool>,
+    /// Render the BrowserIcon as outline, filled or duotone
+    #[prop_or_default]
+    pub variant: IconVariant,
+    /// Color of the filled shapes of the duotone variant
+    #[prop_or("currentColor".to_string())]
+    pub secondary_color: String,
+    /// Opacity of the filled shapes of the duotone variant
+    #[prop_or(0.4)]
+    pub secondary_opacity: f32,
 }
 
 impl Component for BrowserAssets {
     type Properties = Props;
     type Message = Msg;
 
-    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-        Self { props }
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            rtl: is_rtl(),
+            _direction: LayoutDirection::bridge(link.callback(Msg::Direction)),
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        false
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Direction(rtl) => {
+                let changed = self.rtl != rtl;
+                self.rtl = rtl;
+                changed && self.props.rtl.is_none()
+            }
+        }
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
@@ -84,13 +113,24 @@ impl Component for BrowserAssets {
     }
 
     fn view(&self) -> Html {
-        get_icon(
-            self.props.icon.clone(),
-            self.props.size.clone(),
-            self.props.view_box.clone(),
-            self.props.fill.clone(),
-            self.props.class_name.clone(),
-            self.props.id.clone(),
+        get_directional_icon(
+            get_variant_icon(
+                get_icon(
+                    self.props.icon.clone(),
+                    self.props.size.clone(),
+                    self.props.view_box.clone(),
+                    self.props.fill.clone(),
+                    self.props.class_name.clone(),
+                    self.props.id.clone(),
+                ),
+                self.props.icon.fillable(),
+                self.props.variant,
+                &self.props.fill,
+                &self.props.secondary_color,
+                self.props.secondary_opacity,
+            ),
+            self.props.icon.is_directional(),
+            self.props.rtl.unwrap_or(self.rtl),
         )
     }
 }
@@ -101,7 +141,32 @@ pub enum BrowserIcon {
     Chrome,
 }
 
-fn get_icon(
+impl BrowserIcon {
+    /// List of all the BrowserIcons
+    pub const ALL: &'static [BrowserIcon] = &[BrowserIcon::Compass, BrowserIcon::Chrome];
+
+    /// Name of the BrowserIcon in the feather collection
+    pub fn name(&self) -> &'static str {
+        match self {
+            BrowserIcon::Compass => "compass",
+            BrowserIcon::Chrome => "chrome",
+        }
+    }
+
+    /// Whether the BrowserIcon points to a direction and has to be mirrored in right-to-left layouts
+    pub fn is_directional(&self) -> bool {
+        false
+    }
+
+    /// Index of the elements of the BrowserIcon which are closed shapes, filled by the filled and duotone variants
+    pub fn fillable(&self) -> &'static [usize] {
+        match self {
+            BrowserIcon::Compass | BrowserIcon::Chrome => &[0, 1],
+        }
+    }
+}
+
+pub(crate) fn get_icon(
     icon: BrowserIcon,
     size: (String, String),
     view_box: (String, String, String, String),
@@ -123,7 +188,7 @@ fn get_compass(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -140,7 +205,7 @@ fn get_chrome(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -1,6 +1,11 @@
+use crate::direction::{get_directional_icon, is_rtl, LayoutDirection};
+use crate::variant::{get_variant_icon, IconVariant};
+use yew::agent::{Bridge, Bridged};
 use yew::prelude::*;
 
-pub enum Msg {}
+pub enum Msg {
+    Direction(bool),
+}
 
 /// # BusinessAssets
 ///
@@ -43,6 +48,8 @@ pub enum Msg {}
 /// ```
 pub struct BusinessAssets {
     pub props: Props,
+    rtl: bool,
+    _direction: Box<dyn Bridge<LayoutDirection>>,
 }
 
 #[derive(Clone, Properties)]
@@ -64,18 +71,40 @@ pub struct Props {
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
+    /// Mirror the BusinessIcon if it is direction-sensitive. By default it follows the `LayoutDirection` context
+    #[prop_or_default]
+    pub rtl: Option<bool>,
+    /// Render the BusinessIcon as outline, filled or duotone
+    #[prop_or_default]
+    pub variant: IconVariant,
+    /// Color of the filled shapes of the duotone variant
+    #[prop_or("currentColor".to_string())]
+    pub secondary_color: String,
+    /// Opacity of the filled shapes of the duotone variant
+    #[prop_or(0.4)]
+    pub secondary_opacity: f32,
 }
 
 impl Component for BusinessAssets {
     type Properties = Props;
     type Message = Msg;
 
-    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-        Self { props }
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            rtl: is_rtl(),
+            _direction: LayoutDirection::bridge(link.callback(Msg::Direction)),
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        false
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Direction(rtl) => {
+                let changed = self.rtl != rtl;
+                self.rtl = rtl;
+                changed && self.props.rtl.is_none()
+            }
+        }
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
@@ -84,13 +113,24 @@ impl Component for BusinessAssets {
     }
 
     fn view(&self) -> Html {
-        get_icon(
-            self.props.icon.clone(),
-            self.props.size.clone(),
-            self.props.view_box.clone(),
-            self.props.fill.clone(),
-            self.props.class_name.clone(),
-            self.props.id.clone(),
+        get_directional_icon(
+            get_variant_icon(
+                get_icon(
+                    self.props.icon.clone(),
+                    self.props.size.clone(),
+                    self.props.view_box.clone(),
+                    self.props.fill.clone(),
+                    self.props.class_name.clone(),
+                    self.props.id.clone(),
+                ),
+                self.props.icon.fillable(),
+                self.props.variant,
+                &self.props.fill,
+                &self.props.secondary_color,
+                self.props.secondary_opacity,
+            ),
+            self.props.icon.is_directional(),
+            self.props.rtl.unwrap_or(self.rtl),
         )
     }
 }
@@ -109,7 +149,55 @@ pub enum BusinessIcon {
     Award,
 }
 
-fn get_icon(
+impl BusinessIcon {
+    /// List of all the BusinessIcons
+    pub const ALL: &'static [BusinessIcon] = &[
+        BusinessIcon::DollarSign,
+        BusinessIcon::Target,
+        BusinessIcon::BarChart,
+        BusinessIcon::CreditCard,
+        BusinessIcon::TrendingDown,
+        BusinessIcon::Percent,
+        BusinessIcon::BarChart2,
+        BusinessIcon::PieChart,
+        BusinessIcon::TrendingUp,
+        BusinessIcon::Award,
+    ];
+
+    /// Name of the BusinessIcon in the feather collection
+    pub fn name(&self) -> &'static str {
+        match self {
+            BusinessIcon::DollarSign => "dollar-sign",
+            BusinessIcon::Target => "target",
+            BusinessIcon::BarChart => "bar-chart",
+            BusinessIcon::CreditCard => "credit-card",
+            BusinessIcon::TrendingDown => "trending-down",
+            BusinessIcon::Percent => "percent",
+            BusinessIcon::BarChart2 => "bar-chart-2",
+            BusinessIcon::PieChart => "pie-chart",
+            BusinessIcon::TrendingUp => "trending-up",
+            BusinessIcon::Award => "award",
+        }
+    }
+
+    /// Whether the BusinessIcon points to a direction and has to be mirrored in right-to-left layouts
+    pub fn is_directional(&self) -> bool {
+        matches!(self, BusinessIcon::TrendingDown | BusinessIcon::TrendingUp)
+    }
+
+    /// Index of the elements of the BusinessIcon which are closed shapes, filled by the filled and duotone variants
+    pub fn fillable(&self) -> &'static [usize] {
+        match self {
+            BusinessIcon::CreditCard | BusinessIcon::Award => &[0],
+            BusinessIcon::PieChart => &[1],
+            BusinessIcon::Percent => &[1, 2],
+            BusinessIcon::Target => &[0, 1, 2],
+            _ => &[],
+        }
+    }
+}
+
+pub(crate) fn get_icon(
     icon: BusinessIcon,
     size: (String, String),
     view_box: (String, String, String, String),
@@ -139,7 +227,7 @@ fn get_dollarsign(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -156,7 +244,7 @@ fn get_target(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -173,7 +261,7 @@ fn get_barchart(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -190,7 +278,7 @@ fn get_creditcard(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -207,7 +295,7 @@ fn get_trendingdown(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -224,7 +312,7 @@ fn get_percent(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -241,7 +329,7 @@ fn get_barchart2(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -258,7 +346,7 @@ fn get_piechart(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -275,7 +363,7 @@ fn get_trendingup(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -292,7 +380,7 @@ fn get_award(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -1,6 +1,11 @@
+use crate::direction::{get_directional_icon, is_rtl, LayoutDirection};
+use crate::variant::{get_variant_icon, IconVariant};
+use yew::agent::{Bridge, Bridged};
 use yew::prelude::*;
 
-pub enum Msg {}
+pub enum Msg {
+    Direction(bool),
+}
 
 /// # CommunicationAssets
 ///
@@ -43,6 +48,8 @@ pub enum Msg {}
 /// ```
 pub struct CommunicationAssets {
     pub props: Props,
+    rtl: bool,
+    _direction: Box<dyn Bridge<LayoutDirection>>,
 }
 
 #[derive(Clone, Properties)]
@@ -64,18 +71,40 @@ pub struct Props {
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
+    /// Mirror the CommunicationIcon if it is direction-sensitive. By default it follows the `LayoutDirection` context
+    #[prop_or_default]
+    pub rtl: Option<bool>,
+    /// Render the CommunicationIcon as outline, filled or duotone
+    #[prop_or_default]
+    pub variant: IconVariant,
+    /// Color of the filled shapes of the duotone variant
+    #[prop_or("currentColor".to_string())]
+    pub secondary_color: String,
+    /// Opacity of the filled shapes of the duotone variant
+    #[prop_or(0.4)]
+    pub secondary_opacity: f32,
 }
 
 impl Component for CommunicationAssets {
     type Properties = Props;
     type Message = Msg;
 
-    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-        Self { props }
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            rtl: is_rtl(),
+            _direction: LayoutDirection::bridge(link.callback(Msg::Direction)),
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        false
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Direction(rtl) => {
+                let changed = self.rtl != rtl;
+                self.rtl = rtl;
+                changed && self.props.rtl.is_none()
+            }
+        }
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
@@ -84,13 +113,24 @@ impl Component for CommunicationAssets {
     }
 
     fn view(&self) -> Html {
-        get_icon(
-            self.props.icon.clone(),
-            self.props.size.clone(),
-            self.props.view_box.clone(),
-            self.props.fill.clone(),
-            self.props.class_name.clone(),
-            self.props.id.clone(),
+        get_directional_icon(
+            get_variant_icon(
+                get_icon(
+                    self.props.icon.clone(),
+                    self.props.size.clone(),
+                    self.props.view_box.clone(),
+                    self.props.fill.clone(),
+                    self.props.class_name.clone(),
+                    self.props.id.clone(),
+                ),
+                self.props.icon.fillable(),
+                self.props.variant,
+                &self.props.fill,
+                &self.props.secondary_color,
+                self.props.secondary_opacity,
+            ),
+            self.props.icon.is_directional(),
+            self.props.rtl.unwrap_or(self.rtl),
         )
     }
 }
@@ -124,7 +164,102 @@ pub enum CommunicationIcon {
     Wifi,
 }
 
-fn get_icon(
+impl CommunicationIcon {
+    /// List of all the CommunicationIcons
+    pub const ALL: &'static [CommunicationIcon] = &[
+        CommunicationIcon::Users,
+        CommunicationIcon::User,
+        CommunicationIcon::UserPlus,
+        CommunicationIcon::Frown,
+        CommunicationIcon::PhoneMissed,
+        CommunicationIcon::PhoneCall,
+        CommunicationIcon::UserX,
+        CommunicationIcon::PhoneOff,
+        CommunicationIcon::Star,
+        CommunicationIcon::UserCheck,
+        CommunicationIcon::Meh,
+        CommunicationIcon::PhoneOutgoing,
+        CommunicationIcon::Smile,
+        CommunicationIcon::Bluetooth,
+        CommunicationIcon::UserMinus,
+        CommunicationIcon::Voicemail,
+        CommunicationIcon::PhoneIncoming,
+        CommunicationIcon::Phone,
+        CommunicationIcon::WifiOff,
+        CommunicationIcon::Mail,
+        CommunicationIcon::MessageCircle,
+        CommunicationIcon::PhoneForwarded,
+        CommunicationIcon::Heart,
+        CommunicationIcon::MessageSquare,
+        CommunicationIcon::Wifi,
+    ];
+
+    /// Name of the CommunicationIcon in the feather collection
+    pub fn name(&self) -> &'static str {
+        match self {
+            CommunicationIcon::Users => "users",
+            CommunicationIcon::User => "user",
+            CommunicationIcon::UserPlus => "user-plus",
+            CommunicationIcon::Frown => "frown",
+            CommunicationIcon::PhoneMissed => "phone-missed",
+            CommunicationIcon::PhoneCall => "phone-call",
+            CommunicationIcon::UserX => "user-x",
+            CommunicationIcon::PhoneOff => "phone-off",
+            CommunicationIcon::Star => "star",
+            CommunicationIcon::UserCheck => "user-check",
+            CommunicationIcon::Meh => "meh",
+            CommunicationIcon::PhoneOutgoing => "phone-outgoing",
+            CommunicationIcon::Smile => "smile",
+            CommunicationIcon::Bluetooth => "bluetooth",
+            CommunicationIcon::UserMinus => "user-minus",
+            CommunicationIcon::Voicemail => "voicemail",
+            CommunicationIcon::PhoneIncoming => "phone-incoming",
+            CommunicationIcon::Phone => "phone",
+            CommunicationIcon::WifiOff => "wifi-off",
+            CommunicationIcon::Mail => "mail",
+            CommunicationIcon::MessageCircle => "message-circle",
+            CommunicationIcon::PhoneForwarded => "phone-forwarded",
+            CommunicationIcon::Heart => "heart",
+            CommunicationIcon::MessageSquare => "message-square",
+            CommunicationIcon::Wifi => "wifi",
+        }
+    }
+
+    /// Whether the CommunicationIcon points to a direction and has to be mirrored in right-to-left layouts
+    pub fn is_directional(&self) -> bool {
+        false
+    }
+
+    /// Index of the elements of the CommunicationIcon which are closed shapes, filled by the filled and duotone variants
+    pub fn fillable(&self) -> &'static [usize] {
+        match self {
+            // the handset of PhoneCall shares its path with the open arcs of the signal
+            CommunicationIcon::Frown
+            | CommunicationIcon::Star
+            | CommunicationIcon::Meh
+            | CommunicationIcon::Smile
+            | CommunicationIcon::Phone
+            | CommunicationIcon::Mail
+            | CommunicationIcon::MessageCircle
+            | CommunicationIcon::Heart
+            | CommunicationIcon::MessageSquare => &[0],
+            CommunicationIcon::Users
+            | CommunicationIcon::User
+            | CommunicationIcon::UserPlus
+            | CommunicationIcon::UserX
+            | CommunicationIcon::UserCheck
+            | CommunicationIcon::UserMinus => &[1],
+            CommunicationIcon::PhoneMissed
+            | CommunicationIcon::PhoneOutgoing
+            | CommunicationIcon::PhoneIncoming
+            | CommunicationIcon::PhoneForwarded => &[2],
+            CommunicationIcon::Voicemail => &[0, 1],
+            _ => &[],
+        }
+    }
+}
+
+pub(crate) fn get_icon(
     icon: CommunicationIcon,
     size: (String, String),
     view_box: (String, String, String, String),
@@ -171,7 +306,7 @@ fn get_users(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -188,7 +323,7 @@ fn get_user(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -205,7 +340,7 @@ fn get_userplus(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -222,7 +357,7 @@ fn get_frown(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -239,7 +374,7 @@ fn get_phonemissed(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -256,7 +391,7 @@ fn get_phonecall(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -273,7 +408,7 @@ fn get_userx(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -290,7 +425,7 @@ fn get_phoneoff(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -307,7 +442,7 @@ fn get_star(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -324,7 +459,7 @@ fn get_usercheck(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -341,7 +476,7 @@ fn get_meh(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -358,7 +493,7 @@ fn get_phoneoutgoing(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -375,7 +510,7 @@ fn get_smile(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -392,7 +527,7 @@ fn get_bluetooth(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -409,7 +544,7 @@ fn get_userminus(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -426,7 +561,7 @@ fn get_voicemail(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -443,7 +578,7 @@ fn get_phoneincoming(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -460,7 +595,7 @@ fn get_phone(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -477,7 +612,7 @@ fn get_wifioff(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -494,7 +629,7 @@ fn get_mail(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -511,7 +646,7 @@ fn get_messagecircle(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -528,7 +663,7 @@ fn get_phoneforwarded(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -545,7 +680,7 @@ fn get_heart(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -562,7 +697,7 @@ fn get_messagesquare(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -579,7 +714,7 @@ fn get_wifi(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
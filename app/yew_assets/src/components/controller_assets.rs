@@ -1,6 +1,11 @@
+use crate::direction::{get_directional_icon, is_rtl, LayoutDirection};
+use crate::variant::{get_variant_icon, IconVariant};
+use yew::agent::{Bridge, Bridged};
 use yew::prelude::*;
 
-pub enum Msg {}
+pub enum Msg {
+    Direction(bool),
+}
 
 /// # ControllerAssets
 ///
@@ -43,6 +48,8 @@ pub enum Msg {}
 /// ```
 pub struct ControllerAssets {
     pub props: Props,
+    rtl: bool,
+    _direction: Box<dyn Bridge<LayoutDirection>>,
 }
 
 #[derive(Clone, Properties)]
@@ -64,18 +71,40 @@ pub struct Props {
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
+    /// Mirror the ControllerIcon if it is direction-sensitive. By default it follows the `LayoutDirection` context
+    #[prop_or_default]
+    pub rtl: Option<bool>,
+    /// Render the ControllerIcon as outline, filled or duotone
+    #[prop_or_default]
+    pub variant: IconVariant,
+    /// Color of the filled shapes of the duotone variant
+    #[prop_or("currentColor".to_string())]
+    pub secondary_color: String,
+    /// Opacity of the filled shapes of the duotone variant
+    #[prop_or(0.4)]
+    pub secondary_opacity: f32,
 }
 
 impl Component for ControllerAssets {
     type Properties = Props;
     type Message = Msg;
 
-    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-        Self { props }
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            rtl: is_rtl(),
+            _direction: LayoutDirection::bridge(link.callback(Msg::Direction)),
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        false
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Direction(rtl) => {
+                let changed = self.rtl != rtl;
+                self.rtl = rtl;
+                changed && self.props.rtl.is_none()
+            }
+        }
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
@@ -84,13 +113,24 @@ impl Component for ControllerAssets {
     }
 
     fn view(&self) -> Html {
-        get_icon(
-            self.props.icon.clone(),
-            self.props.size.clone(),
-            self.props.view_box.clone(),
-            self.props.fill.clone(),
-            self.props.class_name.clone(),
-            self.props.id.clone(),
+        get_directional_icon(
+            get_variant_icon(
+                get_icon(
+                    self.props.icon.clone(),
+                    self.props.size.clone(),
+                    self.props.view_box.clone(),
+                    self.props.fill.clone(),
+                    self.props.class_name.clone(),
+                    self.props.id.clone(),
+                ),
+                self.props.icon.fillable(),
+                self.props.variant,
+                &self.props.fill,
+                &self.props.secondary_color,
+                self.props.secondary_opacity,
+            ),
+            self.props.icon.is_directional(),
+            self.props.rtl.unwrap_or(self.rtl),
         )
     }
 }
@@ -146,7 +186,171 @@ pub enum ControllerIcon {
     ChevronsUp,
 }
 
-fn get_icon(
+impl ControllerIcon {
+    /// List of all the ControllerIcons
+    pub const ALL: &'static [ControllerIcon] = &[
+        ControllerIcon::ArrowRightCircle,
+        ControllerIcon::CornerLeftDown,
+        ControllerIcon::ArrowRight,
+        ControllerIcon::PauseCircle,
+        ControllerIcon::ArrowLeft,
+        ControllerIcon::ChevronDown,
+        ControllerIcon::StopCircle,
+        ControllerIcon::Volume2,
+        ControllerIcon::ArrowDownCircle,
+        ControllerIcon::RotateCcw,
+        ControllerIcon::Volume,
+        ControllerIcon::CornerDownLeft,
+        ControllerIcon::CornerRightDown,
+        ControllerIcon::RefreshCcw,
+        ControllerIcon::CornerDownRight,
+        ControllerIcon::ArrowLeftCircle,
+        ControllerIcon::MicOff,
+        ControllerIcon::ArrowUpLeft,
+        ControllerIcon::ArrowDownLeft,
+        ControllerIcon::ArrowDown,
+        ControllerIcon::ChevronRight,
+        ControllerIcon::ChevronsDown,
+        ControllerIcon::Power,
+        ControllerIcon::SkipForward,
+        ControllerIcon::Rewind,
+        ControllerIcon::Pause,
+        ControllerIcon::Volume1,
+        ControllerIcon::CornerUpLeft,
+        ControllerIcon::ChevronUp,
+        ControllerIcon::CornerRightUp,
+        ControllerIcon::ArrowDownRight,
+        ControllerIcon::CornerLeftUp,
+        ControllerIcon::ArrowUp,
+        ControllerIcon::Repeat,
+        ControllerIcon::Play,
+        ControllerIcon::ChevronsLeft,
+        ControllerIcon::PlayCircle,
+        ControllerIcon::ArrowUpCircle,
+        ControllerIcon::ChevronLeft,
+        ControllerIcon::FastForward,
+        ControllerIcon::Mic,
+        ControllerIcon::ChevronsRight,
+        ControllerIcon::SkipBack,
+        ControllerIcon::ArrowUpRight,
+        ControllerIcon::CornerUpRight,
+        ControllerIcon::VolumeX,
+        ControllerIcon::ChevronsUp,
+    ];
+
+    /// Name of the ControllerIcon in the feather collection
+    pub fn name(&self) -> &'static str {
+        match self {
+            ControllerIcon::ArrowRightCircle => "arrow-right-circle",
+            ControllerIcon::CornerLeftDown => "corner-left-down",
+            ControllerIcon::ArrowRight => "arrow-right",
+            ControllerIcon::PauseCircle => "pause-circle",
+            ControllerIcon::ArrowLeft => "arrow-left",
+            ControllerIcon::ChevronDown => "chevron-down",
+            ControllerIcon::StopCircle => "stop-circle",
+            ControllerIcon::Volume2 => "volume-2",
+            ControllerIcon::ArrowDownCircle => "arrow-down-circle",
+            ControllerIcon::RotateCcw => "rotate-ccw",
+            ControllerIcon::Volume => "volume",
+            ControllerIcon::CornerDownLeft => "corner-down-left",
+            ControllerIcon::CornerRightDown => "corner-right-down",
+            ControllerIcon::RefreshCcw => "refresh-ccw",
+            ControllerIcon::CornerDownRight => "corner-down-right",
+            ControllerIcon::ArrowLeftCircle => "arrow-left-circle",
+            ControllerIcon::MicOff => "mic-off",
+            ControllerIcon::ArrowUpLeft => "arrow-up-left",
+            ControllerIcon::ArrowDownLeft => "arrow-down-left",
+            ControllerIcon::ArrowDown => "arrow-down",
+            ControllerIcon::ChevronRight => "chevron-right",
+            ControllerIcon::ChevronsDown => "chevrons-down",
+            ControllerIcon::Power => "power",
+            ControllerIcon::SkipForward => "skip-forward",
+            ControllerIcon::Rewind => "rewind",
+            ControllerIcon::Pause => "pause",
+            ControllerIcon::Volume1 => "volume-1",
+            ControllerIcon::CornerUpLeft => "corner-up-left",
+            ControllerIcon::ChevronUp => "chevron-up",
+            ControllerIcon::CornerRightUp => "corner-right-up",
+            ControllerIcon::ArrowDownRight => "arrow-down-right",
+            ControllerIcon::CornerLeftUp => "corner-left-up",
+            ControllerIcon::ArrowUp => "arrow-up",
+            ControllerIcon::Repeat => "repeat",
+            ControllerIcon::Play => "play",
+            ControllerIcon::ChevronsLeft => "chevrons-left",
+            ControllerIcon::PlayCircle => "play-circle",
+            ControllerIcon::ArrowUpCircle => "arrow-up-circle",
+            ControllerIcon::ChevronLeft => "chevron-left",
+            ControllerIcon::FastForward => "fast-forward",
+            ControllerIcon::Mic => "mic",
+            ControllerIcon::ChevronsRight => "chevrons-right",
+            ControllerIcon::SkipBack => "skip-back",
+            ControllerIcon::ArrowUpRight => "arrow-up-right",
+            ControllerIcon::CornerUpRight => "corner-up-right",
+            ControllerIcon::VolumeX => "volume-x",
+            ControllerIcon::ChevronsUp => "chevrons-up",
+        }
+    }
+
+    /// Whether the ControllerIcon points to a direction and has to be mirrored in right-to-left layouts
+    pub fn is_directional(&self) -> bool {
+        matches!(
+            self,
+            ControllerIcon::ArrowRightCircle
+                | ControllerIcon::CornerLeftDown
+                | ControllerIcon::ArrowRight
+                | ControllerIcon::ArrowLeft
+                | ControllerIcon::CornerDownLeft
+                | ControllerIcon::CornerRightDown
+                | ControllerIcon::CornerDownRight
+                | ControllerIcon::ArrowLeftCircle
+                | ControllerIcon::ArrowUpLeft
+                | ControllerIcon::ArrowDownLeft
+                | ControllerIcon::ChevronRight
+                | ControllerIcon::SkipForward
+                | ControllerIcon::Rewind
+                | ControllerIcon::CornerUpLeft
+                | ControllerIcon::CornerRightUp
+                | ControllerIcon::ArrowDownRight
+                | ControllerIcon::CornerLeftUp
+                | ControllerIcon::Play
+                | ControllerIcon::ChevronsLeft
+                | ControllerIcon::PlayCircle
+                | ControllerIcon::ChevronLeft
+                | ControllerIcon::FastForward
+                | ControllerIcon::ChevronsRight
+                | ControllerIcon::SkipBack
+                | ControllerIcon::ArrowUpRight
+                | ControllerIcon::CornerUpRight
+        )
+    }
+
+    /// Index of the elements of the ControllerIcon which are closed shapes, filled by the filled and duotone variants
+    pub fn fillable(&self) -> &'static [usize] {
+        match self {
+            ControllerIcon::ArrowRightCircle
+            | ControllerIcon::PauseCircle
+            | ControllerIcon::Volume2
+            | ControllerIcon::ArrowDownCircle
+            | ControllerIcon::Volume
+            | ControllerIcon::ArrowLeftCircle
+            | ControllerIcon::SkipForward
+            | ControllerIcon::Volume1
+            | ControllerIcon::Play
+            | ControllerIcon::ArrowUpCircle
+            | ControllerIcon::Mic
+            | ControllerIcon::SkipBack
+            | ControllerIcon::VolumeX => &[0],
+            ControllerIcon::StopCircle
+            | ControllerIcon::Rewind
+            | ControllerIcon::Pause
+            | ControllerIcon::PlayCircle
+            | ControllerIcon::FastForward => &[0, 1],
+            _ => &[],
+        }
+    }
+}
+
+pub(crate) fn get_icon(
     icon: ControllerIcon,
     size: (String, String),
     view_box: (String, String, String, String),
@@ -223,7 +427,7 @@ fn get_arrowrightcircle(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -240,7 +444,7 @@ fn get_cornerleftdown(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -257,7 +461,7 @@ fn get_arrowright(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -274,7 +478,7 @@ fn get_pausecircle(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -291,7 +495,7 @@ fn get_arrowleft(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -308,7 +512,7 @@ fn get_chevrondown(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -325,7 +529,7 @@ fn get_stopcircle(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -342,7 +546,7 @@ fn get_volume2(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -359,7 +563,7 @@ fn get_arrowdowncircle(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -376,7 +580,7 @@ fn get_rotateccw(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -393,7 +597,7 @@ fn get_volume(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -410,7 +614,7 @@ fn get_cornerdownleft(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -427,7 +631,7 @@ fn get_cornerrightdown(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -444,7 +648,7 @@ fn get_refreshccw(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -461,7 +665,7 @@ fn get_cornerdownright(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -478,7 +682,7 @@ fn get_arrowleftcircle(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -495,7 +699,7 @@ fn get_micoff(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -512,7 +716,7 @@ fn get_arrowupleft(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -529,7 +733,7 @@ fn get_arrowdownleft(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -546,7 +750,7 @@ fn get_arrowdown(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -563,7 +767,7 @@ fn get_chevronright(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -580,7 +784,7 @@ fn get_chevronsdown(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -597,7 +801,7 @@ fn get_power(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -614,7 +818,7 @@ fn get_skipforward(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -631,7 +835,7 @@ fn get_rewind(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -648,7 +852,7 @@ fn get_pause(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -665,7 +869,7 @@ fn get_volume1(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -682,7 +886,7 @@ fn get_cornerupleft(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -699,7 +903,7 @@ fn get_chevronup(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -716,7 +920,7 @@ fn get_cornerrightup(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -733,7 +937,7 @@ fn get_arrowdownright(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -750,7 +954,7 @@ fn get_cornerleftup(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -767,7 +971,7 @@ fn get_arrowup(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -784,7 +988,7 @@ fn get_repeat(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -801,7 +1005,7 @@ fn get_play(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -818,7 +1022,7 @@ fn get_chevronsleft(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -835,7 +1039,7 @@ fn get_playcircle(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -852,7 +1056,7 @@ fn get_arrowupcircle(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -869,7 +1073,7 @@ fn get_chevronleft(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -886,7 +1090,7 @@ fn get_fastforward(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -903,7 +1107,7 @@ fn get_mic(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -920,7 +1124,7 @@ fn get_chevronsright(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -937,7 +1141,7 @@ fn get_skipback(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -954,7 +1158,7 @@ fn get_arrowupright(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -971,7 +1175,7 @@ fn get_cornerupright(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -988,7 +1192,7 @@ fn get_volumex(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -1005,7 +1209,7 @@ fn get_chevronsup(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
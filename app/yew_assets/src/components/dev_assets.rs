@@ -1,6 +1,11 @@
+use crate::direction::{get_directional_icon, is_rtl, LayoutDirection};
+use crate::variant::{get_variant_icon, IconVariant};
+use yew::agent::{Bridge, Bridged};
 use yew::prelude::*;
 
-pub enum Msg {}
+pub enum Msg {
+    Direction(bool),
+}
 
 /// # DevAssets
 ///
@@ -43,6 +48,8 @@ pub enum Msg {}
 /// ```
 pub struct DevAssets {
     pub props: Props,
+    rtl: bool,
+    _direction: Box<dyn Bridge<LayoutDirection>>,
 }
 
 #[derive(Clone, Properties)]
@@ -64,18 +71,40 @@ pub struct Props {
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
+    /// Mirror the DevIcon if it is direction-sensitive. By default it follows the `LayoutDirection` context
+    #[prop_or_default]
+    pub rtl: Option<bool>,
+    /// Render the DevIcon as outline, filled or duotone
+    #[prop_or_default]
+    pub variant: IconVariant,
+    /// Color of the filled shapes of the duotone variant
+    #[prop_or("currentColor".to_string())]
+    pub secondary_color: String,
+    /// Opacity of the filled shapes of the duotone variant
+    #[prop_or(0.4)]
+    pub secondary_opacity: f32,
 }
 
 impl Component for DevAssets {
     type Properties = Props;
     type Message = Msg;
 
-    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-        Self { props }
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            rtl: is_rtl(),
+            _direction: LayoutDirection::bridge(link.callback(Msg::Direction)),
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        false
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Direction(rtl) => {
+                let changed = self.rtl != rtl;
+                self.rtl = rtl;
+                changed && self.props.rtl.is_none()
+            }
+        }
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
@@ -84,13 +113,24 @@ impl Component for DevAssets {
     }
 
     fn view(&self) -> Html {
-        get_icon(
-            self.props.icon.clone(),
-            self.props.size.clone(),
-            self.props.view_box.clone(),
-            self.props.fill.clone(),
-            self.props.class_name.clone(),
-            self.props.id.clone(),
+        get_directional_icon(
+            get_variant_icon(
+                get_icon(
+                    self.props.icon.clone(),
+                    self.props.size.clone(),
+                    self.props.view_box.clone(),
+                    self.props.fill.clone(),
+                    self.props.class_name.clone(),
+                    self.props.id.clone(),
+                ),
+                self.props.icon.fillable(),
+                self.props.variant,
+                &self.props.fill,
+                &self.props.secondary_color,
+                self.props.secondary_opacity,
+            ),
+            self.props.icon.is_directional(),
+            self.props.rtl.unwrap_or(self.rtl),
         )
     }
 }
@@ -118,7 +158,81 @@ pub enum DevIcon {
     Command,
 }
 
-fn get_icon(
+impl DevIcon {
+    /// List of all the DevIcons
+    pub const ALL: &'static [DevIcon] = &[
+        DevIcon::Database,
+        DevIcon::GitBranch,
+        DevIcon::Feather,
+        DevIcon::Cpu,
+        DevIcon::GitPullRequest,
+        DevIcon::Github,
+        DevIcon::Codesandbox,
+        DevIcon::Server,
+        DevIcon::GitCommit,
+        DevIcon::Figma,
+        DevIcon::Code,
+        DevIcon::Gitlab,
+        DevIcon::Droplet,
+        DevIcon::Trello,
+        DevIcon::Codepen,
+        DevIcon::Terminal,
+        DevIcon::GitMerge,
+        DevIcon::Framer,
+        DevIcon::Command,
+    ];
+
+    /// Name of the DevIcon in the feather collection
+    pub fn name(&self) -> &'static str {
+        match self {
+            DevIcon::Database => "database",
+            DevIcon::GitBranch => "git-branch",
+            DevIcon::Feather => "feather",
+            DevIcon::Cpu => "cpu",
+            DevIcon::GitPullRequest => "git-pull-request",
+            DevIcon::Github => "github",
+            DevIcon::Codesandbox => "codesandbox",
+            DevIcon::Server => "server",
+            DevIcon::GitCommit => "git-commit",
+            DevIcon::Figma => "figma",
+            DevIcon::Code => "code",
+            DevIcon::Gitlab => "gitlab",
+            DevIcon::Droplet => "droplet",
+            DevIcon::Trello => "trello",
+            DevIcon::Codepen => "codepen",
+            DevIcon::Terminal => "terminal",
+            DevIcon::GitMerge => "git-merge",
+            DevIcon::Framer => "framer",
+            DevIcon::Command => "command",
+        }
+    }
+
+    /// Whether the DevIcon points to a direction and has to be mirrored in right-to-left layouts
+    pub fn is_directional(&self) -> bool {
+        false
+    }
+
+    /// Index of the elements of the DevIcon which are closed shapes, filled by the filled and duotone variants
+    pub fn fillable(&self) -> &'static [usize] {
+        match self {
+            DevIcon::Database
+            | DevIcon::Feather
+            | DevIcon::Codesandbox
+            | DevIcon::GitCommit
+            | DevIcon::Gitlab
+            | DevIcon::Droplet
+            | DevIcon::Codepen
+            | DevIcon::Command => &[0],
+            DevIcon::Cpu | DevIcon::GitPullRequest | DevIcon::Server | DevIcon::GitMerge => &[0, 1],
+            DevIcon::GitBranch => &[1, 2],
+            DevIcon::Trello => &[0, 1, 2],
+            DevIcon::Figma => &[0, 1, 2, 3, 4],
+            _ => &[],
+        }
+    }
+}
+
+pub(crate) fn get_icon(
     icon: DevIcon,
     size: (String, String),
     view_box: (String, String, String, String),
@@ -157,7 +271,7 @@ fn get_database(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -174,7 +288,7 @@ fn get_gitbranch(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -191,7 +305,7 @@ fn get_feather(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -208,7 +322,7 @@ fn get_cpu(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -225,7 +339,7 @@ fn get_gitpullrequest(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -242,7 +356,7 @@ fn get_github(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -259,7 +373,7 @@ fn get_codesandbox(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -276,7 +390,7 @@ fn get_server(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -293,7 +407,7 @@ fn get_gitcommit(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -310,7 +424,7 @@ fn get_figma(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -327,7 +441,7 @@ fn get_code(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -344,7 +458,7 @@ fn get_gitlab(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -361,7 +475,7 @@ fn get_droplet(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -378,7 +492,7 @@ fn get_trello(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -395,7 +509,7 @@ fn get_codepen(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -412,7 +526,7 @@ fn get_terminal(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -429,7 +543,7 @@ fn get_gitmerge(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -446,7 +560,7 @@ fn get_framer(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
@@ -463,7 +577,7 @@ fn get_command(
     id: String,
 ) -> Html {
     html! {
-        <svg xmlns="http://www.w3.org/2000/svg" witdh=size.0 height=size.1 viewBox=format!("{} {} {} {}",
+        <svg xmlns="http://www.w3.org/2000/svg" width=size.0 height=size.1 viewBox=format!("{} {} {} {}",
             view_box.0,
             view_box.1,
             view_box.2,
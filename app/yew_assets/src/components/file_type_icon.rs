@@ -0,0 +1,104 @@
+use crate::{file_icon_for, FileIconTable};
+use std::rc::Rc;
+use yew::prelude::*;
+
+pub enum Msg {}
+
+/// # FileTypeIcon
+///
+/// Add the svg icon which matches best a file name or a MIME type, using `file_icon_for`
+///
+/// ## Feature
+/// file_assets
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_assets::file_type_icon::FileTypeIcon;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App {}
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         <FileTypeIcon
+///             name = "report.pdf"
+///             size = ("30".to_string(),"30".to_string())
+///         />
+///     }
+/// }
+/// ```
+pub struct FileTypeIcon {
+    pub props: Props,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    /// File name, path or MIME type of the file
+    pub name: String,
+    /// Lookup table of the icons. By default the table of `file_icon_for`, set with `set_file_icon_table`
+    #[prop_or_default]
+    pub table: Option<Rc<FileIconTable>>,
+    /// Size of the icon
+    #[prop_or(("24".to_string(),"24".to_string()))]
+    pub size: (String, String),
+    /// Defines the position and dimension of the icon
+    #[prop_or(("0".to_string(),"0".to_string(),"24".to_string(),"24".to_string()))]
+    pub view_box: (String, String, String, String),
+    /// Fill the color of the asset
+    #[prop_or("none".to_string())]
+    pub fill: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+}
+
+impl Component for FileTypeIcon {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let icon = match &self.props.table {
+            Some(table) => table.icon_for(&self.props.name),
+            None => file_icon_for(&self.props.name),
+        };
+
+        icon.html(
+            self.props.size.clone(),
+            self.props.view_box.clone(),
+            self.props.fill.clone(),
+            self.props.class_name.clone(),
+            self.props.id.clone(),
+        )
+    }
+}
@@ -0,0 +1,378 @@
+use crate::communication_assets::CommunicationIcon;
+use crate::variant::{get_variant_icon, IconVariant};
+use crate::Icon;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use yew::events::KeyboardEvent;
+use yew::prelude::*;
+
+/// Counter to get unique ids for the clip paths of every rating in the page
+static RATING_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub enum Msg {
+    Hover(f32),
+    Leave,
+    Select(f32),
+    Key(KeyboardEvent),
+}
+
+/// # IconRating
+///
+/// Row of icons to show or select a rating, `CommunicationIcon::Star` by default.
+/// Fractional values fill part of the icon, and it can be controlled with the mouse,
+/// with a preview on hover, or with the arrow keys as an ARIA slider.
+/// It doesn't keep the value, `onchange` gets the new value and the parent sets it back in `value`
+///
+/// ## Feature
+/// communication_assets
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_assets::icon_rating::IconRating;
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+///     rating: f32,
+/// }
+///
+/// impl Component for App {
+///     type Message = f32;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App { link, rating: 3.5 }
+///     }
+///
+///     fn update(&mut self, rating: Self::Message) -> ShouldRender {
+///         self.rating = rating;
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         <IconRating
+///             value = self.rating
+///             max = 5
+///             onchange = self.link.callback(|rating| rating)
+///         />
+///     }
+/// }
+/// ```
+pub struct IconRating {
+    pub link: ComponentLink<Self>,
+    pub props: Props,
+    id: usize,
+    hover: Option<f32>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    /// Rating to show, between 0 and `max`
+    #[prop_or_default]
+    pub value: f32,
+    /// Number of icons of the rating
+    #[prop_or(5)]
+    pub max: u32,
+    /// Smallest change of the rating with the mouse and the keyboard, 0.5 selects half icons
+    #[prop_or(0.5)]
+    pub step: f32,
+    /// Only show the rating without changing it
+    #[prop_or_default]
+    pub readonly: bool,
+    /// Called with the new rating when it is selected
+    #[prop_or_default]
+    pub onchange: Callback<f32>,
+    /// Icon of the rating
+    #[prop_or(CommunicationIcon::Star.into())]
+    pub icon: Icon,
+    /// Color of the filled part of the icons
+    #[prop_or("#ffb400".to_string())]
+    pub color: String,
+    /// Color of the empty part of the icons
+    #[prop_or("currentColor".to_string())]
+    pub empty_color: String,
+    /// Size of every icon
+    #[prop_or(("24".to_string(),"24".to_string()))]
+    pub size: (String, String),
+    /// Label read by the screen readers
+    #[prop_or("Rating".to_string())]
+    pub label: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+}
+
+impl Component for IconRating {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            id: RATING_ID.fetch_add(1, Ordering::Relaxed),
+            hover: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        if self.props.readonly {
+            return false;
+        }
+
+        match msg {
+            Msg::Hover(value) => {
+                let changed = self.hover != Some(value);
+                self.hover = Some(value);
+                changed
+            }
+            Msg::Leave => self.hover.take().is_some(),
+            Msg::Select(value) => {
+                self.props.onchange.emit(value);
+                false
+            }
+            Msg::Key(event) => {
+                let (value, max, step) = (self.props.value, self.props.max, self.props.step);
+                let value = match event.key().as_str() {
+                    "ArrowRight" | "ArrowUp" => get_next_value(value, max, step),
+                    "ArrowLeft" | "ArrowDown" => get_previous_value(value, max, step),
+                    "Home" => 0.0,
+                    "End" => max as f32,
+                    _ => return false,
+                };
+
+                event.prevent_default();
+                self.props.onchange.emit(value);
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let value = self
+            .hover
+            .unwrap_or(self.props.value)
+            .clamp(0.0, self.props.max as f32);
+        let value_text = format!("{} of {}", self.props.value, self.props.max);
+        let tab_index = if self.props.readonly { "-1" } else { "0" };
+
+        html! {
+            <div
+                class=self.props.class_name.clone()
+                id=self.props.id.clone()
+                style="display: inline-flex; line-height: 0"
+                role="slider"
+                tabindex=tab_index
+                aria-label=self.props.label.clone()
+                aria-valuemin="0"
+                aria-valuemax=self.props.max.to_string()
+                aria-valuenow=self.props.value.to_string()
+                aria-valuetext=value_text
+                aria-readonly=self.props.readonly.to_string()
+                onkeydown=self.link.callback(Msg::Key)
+                onmouseleave=self.link.callback(|_| Msg::Leave)
+            >
+                {(0..self.props.max).map(|index| self.get_icon(index, value)).collect::<Html>()}
+            </div>
+        }
+    }
+}
+
+impl IconRating {
+    /// Icon with the empty outline and the filled icon on top, clipped to the fraction of the rating
+    fn get_icon(&self, index: u32, value: f32) -> Html {
+        let fraction = (value - index as f32).clamp(0.0, 1.0);
+        let clip_id = format!("yew-assets-rating-{}-{}", self.id, index);
+        let view_box = (
+            "0".to_string(),
+            "0".to_string(),
+            "24".to_string(),
+            "24".to_string(),
+        );
+        let size = ("24".to_string(), "24".to_string());
+        let empty = self.props.icon.html(
+            size.clone(),
+            view_box.clone(),
+            "none".to_string(),
+            String::new(),
+            String::new(),
+        );
+        let filled = get_variant_icon(
+            self.props.icon.html(
+                size,
+                view_box,
+                "none".to_string(),
+                String::new(),
+                String::new(),
+            ),
+            self.props.icon.fillable(),
+            IconVariant::Filled,
+            "none",
+            "",
+            1.0,
+        );
+
+        html! {
+            <svg
+                xmlns="http://www.w3.org/2000/svg"
+                width=self.props.size.0.clone()
+                height=self.props.size.1.clone()
+                viewBox="0 0 24 24"
+            >
+                <defs>
+                    <clipPath id=clip_id.clone()>
+                        <rect x="0" y="0" width=(fraction * 24.0).to_string() height="24"></rect>
+                    </clipPath>
+                </defs>
+                <g style=format!("color: {}", self.props.empty_color)>{empty}</g>
+                <g style=format!("color: {}", self.props.color) clip-path=format!("url(#{})", clip_id)>{filled}</g>
+                {self.get_hit_zones(index)}
+            </svg>
+        }
+    }
+
+    /// Transparent zones over the icon, one for every step, to preview and select the rating
+    fn get_hit_zones(&self, index: u32) -> Html {
+        if self.props.readonly {
+            return html! {};
+        }
+
+        let values = get_zone_values(index, self.props.step);
+        let width = 24.0 / values.len() as f32;
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(zone, value)| {
+                html! {
+                    <rect
+                        x=(zone as f32 * width).to_string()
+                        y="0"
+                        width=width.to_string()
+                        height="24"
+                        fill="transparent"
+                        style="cursor: pointer"
+                        onmouseenter=self.link.callback(move |_| Msg::Hover(value))
+                        onclick=self.link.callback(move |_| Msg::Select(value))
+                    ></rect>
+                }
+            })
+            .collect::<Html>()
+    }
+}
+
+/// Ratings selected by the zones of the icon at `index`, one for every step.
+/// The last zone always selects the whole icon, even when the step doesn't divide it
+fn get_zone_values(index: u32, step: f32) -> Vec<f32> {
+    let step = get_step(step);
+    let zones = ((1.0 / step).round() as u32).max(1);
+
+    (1..=zones)
+        .map(|zone| {
+            if zone == zones {
+                (index + 1) as f32
+            } else {
+                index as f32 + zone as f32 * step
+            }
+        })
+        .collect()
+}
+
+/// Ratings of all the zones, the same for the mouse and the keyboard, starting with 0
+fn get_values(max: u32, step: f32) -> Vec<f32> {
+    std::iter::once(0.0)
+        .chain((0..max).flat_map(|index| get_zone_values(index, step)))
+        .collect()
+}
+
+/// Rating of the next zone, used by the arrow keys
+fn get_next_value(value: f32, max: u32, step: f32) -> f32 {
+    get_values(max, step)
+        .into_iter()
+        .find(|zone| *zone > value + 1e-4)
+        .unwrap_or(max as f32)
+}
+
+/// Rating of the previous zone, used by the arrow keys
+fn get_previous_value(value: f32, max: u32, step: f32) -> f32 {
+    get_values(max, step)
+        .into_iter()
+        .rev()
+        .find(|zone| *zone < value - 1e-4)
+        .unwrap_or(0.0)
+}
+
+/// Steps out of the range of one icon change a whole icon
+fn get_step(step: f32) -> f32 {
+    if step > 0.0 && step <= 1.0 {
+        step
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_values(values: Vec<f32>, expected: &[f32]) {
+        assert_eq!(values.len(), expected.len(), "{:?}", values);
+
+        for (value, expected) in values.iter().zip(expected.iter()) {
+            assert!((value - expected).abs() < 1e-5, "{:?}", values);
+        }
+    }
+
+    #[test]
+    fn should_select_the_steps_of_the_icon() {
+        assert_values(get_zone_values(0, 0.5), &[0.5, 1.0]);
+        assert_values(get_zone_values(2, 0.5), &[2.5, 3.0]);
+        assert_values(get_zone_values(1, 0.25), &[1.25, 1.5, 1.75, 2.0]);
+        assert_values(get_zone_values(4, 1.0), &[5.0]);
+    }
+
+    #[test]
+    fn should_select_the_whole_icon_in_the_last_zone() {
+        assert_values(get_zone_values(0, 0.3), &[0.3, 0.6, 1.0]);
+        assert_values(get_zone_values(3, 0.4), &[3.4, 3.8, 4.0]);
+        assert_values(get_zone_values(0, 0.7), &[1.0]);
+    }
+
+    #[test]
+    fn should_select_whole_icons_with_invalid_steps() {
+        assert_values(get_zone_values(1, 0.0), &[2.0]);
+        assert_values(get_zone_values(1, -0.5), &[2.0]);
+        assert_values(get_zone_values(1, 2.0), &[2.0]);
+    }
+
+    #[test]
+    fn should_move_the_keyboard_through_the_zones() {
+        assert_values(get_values(2, 0.3), &[0.0, 0.3, 0.6, 1.0, 1.3, 1.6, 2.0]);
+        assert_values(
+            vec![
+                get_next_value(0.6, 2, 0.3),
+                get_next_value(0.9, 2, 0.3),
+                get_next_value(2.0, 2, 0.3),
+                get_previous_value(1.0, 2, 0.3),
+                get_previous_value(0.9, 2, 0.3),
+                get_previous_value(0.0, 2, 0.3),
+            ],
+            &[1.0, 1.0, 2.0, 0.6, 0.6, 0.0],
+        );
+        assert_values(
+            vec![get_next_value(3.5, 5, 0.5), get_previous_value(3.5, 5, 0.5)],
+            &[4.0, 3.0],
+        );
+    }
+}
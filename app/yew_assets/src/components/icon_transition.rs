@@ -0,0 +1,203 @@
+#[cfg(feature = "morph")]
+use crate::morph::get_morph;
+use crate::Icon;
+use yew::prelude::*;
+
+pub enum Msg {}
+
+/// # IconTransition
+///
+/// Animate the change between two icons, like play and pause or menu and close
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_assets::controller_assets::ControllerIcon;
+/// use yew_assets::icon_transition::{IconTransition, Transition};
+///
+/// pub struct App {
+///     playing: bool,
+/// }
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App { playing: false }
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         self.playing = !self.playing;
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         <IconTransition
+///             from = ControllerIcon::Play
+///             to = ControllerIcon::Pause
+///             active = self.playing
+///             transition = Transition::Rotate
+///             duration = 300
+///         />
+///     }
+/// }
+/// ```
+pub struct IconTransition {
+    pub props: Props,
+}
+
+/// Animation used to change between the icons
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Transition {
+    /// Fade out the current icon while the other fades in
+    #[default]
+    CrossFade,
+    /// Rotate and shrink the current icon while the other grows rotating in the opposite direction
+    Rotate,
+    /// Morph the strokes of the current icon into the other. Both icons are converted into
+    /// the same number of cubic curves, the strokes without a pair shrinking until they hide.
+    /// Browsers without the `d` css property change the icons without animation.
+    /// Without the `morph` feature it's a `CrossFade`
+    Morph,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    /// Icon shown while it is not active
+    pub from: Icon,
+    /// Icon shown while it is active
+    pub to: Icon,
+    /// Show the `to` icon
+    #[prop_or_default]
+    pub active: bool,
+    /// Animation used to change between the icons
+    #[prop_or_default]
+    pub transition: Transition,
+    /// Duration of the animation in milliseconds
+    #[prop_or(200)]
+    pub duration: u32,
+    /// Easing function of the animation
+    #[prop_or("ease-in-out".to_string())]
+    pub easing: String,
+    /// Size of the icons
+    #[prop_or(("24".to_string(),"24".to_string()))]
+    pub size: (String, String),
+    /// Defines the position and dimension of the icons
+    #[prop_or(("0".to_string(),"0".to_string(),"24".to_string(),"24".to_string()))]
+    pub view_box: (String, String, String, String),
+    /// Fill the color of the icons
+    #[prop_or("none".to_string())]
+    pub fill: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+}
+
+impl Component for IconTransition {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let from = self.get_icon(&self.props.from);
+        let to = self.get_icon(&self.props.to);
+
+        #[cfg(feature = "morph")]
+        if self.props.transition == Transition::Morph {
+            let timing = format!("{}, {}", self.get_timing("d"), self.get_timing("opacity"));
+
+            if let Some(morph) = get_morph(&from, &to, self.props.active, &timing) {
+                return morph;
+            }
+        }
+
+        let (width, height) = (
+            get_css_length(&self.props.size.0),
+            get_css_length(&self.props.size.1),
+        );
+        let timing = format!(
+            "{}, {}",
+            self.get_timing("opacity"),
+            self.get_timing("transform")
+        );
+        let (from_transform, to_transform) = match self.props.transition {
+            Transition::Rotate => ("rotate(90deg) scale(0.5)", "rotate(-90deg) scale(0.5)"),
+            _ => ("none", "none"),
+        };
+        let hidden_style = |transform: &str| format!("opacity: 0; transform: {}", transform);
+        let (from_style, to_style) = if self.props.active {
+            (hidden_style(from_transform), "opacity: 1".to_string())
+        } else {
+            ("opacity: 1".to_string(), hidden_style(to_transform))
+        };
+
+        html! {
+            <span
+                class=self.props.class_name.clone()
+                id=self.props.id.clone()
+                style=format!("position: relative; display: inline-block; width: {}; height: {}; line-height: 0", width, height)
+            >
+                <span
+                    aria-hidden=self.props.active.to_string()
+                    style=format!("position: absolute; top: 0; left: 0; transform-origin: center; transition: {}; {}", timing, from_style)
+                >
+                    {from}
+                </span>
+                <span
+                    aria-hidden=(!self.props.active).to_string()
+                    style=format!("position: absolute; top: 0; left: 0; transform-origin: center; transition: {}; {}", timing, to_style)
+                >
+                    {to}
+                </span>
+            </span>
+        }
+    }
+}
+
+impl IconTransition {
+    fn get_icon(&self, icon: &Icon) -> Html {
+        icon.html(
+            self.props.size.clone(),
+            self.props.view_box.clone(),
+            self.props.fill.clone(),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    fn get_timing(&self, property: &str) -> String {
+        format!(
+            "{} {}ms {}",
+            property, self.props.duration, self.props.easing
+        )
+    }
+}
+
+/// Svg lengths without unit are pixels, css needs the unit
+fn get_css_length(length: &str) -> String {
+    if length.parse::<f64>().is_ok() {
+        format!("{}px", length)
+    } else {
+        length.to_string()
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "device_assets")]
+pub mod battery_level;
 #[cfg(feature = "browser_assets")]
 pub mod browser_assets;
 #[cfg(feature = "business_assets")]
@@ -16,6 +18,11 @@ pub mod editing_assets;
 pub mod env_assets;
 #[cfg(feature = "file_assets")]
 pub mod file_assets;
+#[cfg(feature = "file_assets")]
+pub mod file_type_icon;
+#[cfg(feature = "communication_assets")]
+pub mod icon_rating;
+pub mod icon_transition;
 #[cfg(feature = "info_assets")]
 pub mod info_assets;
 #[cfg(feature = "multimedia_assets")]
@@ -24,7 +31,29 @@ pub mod multimedia_assets;
 pub mod nav_assets;
 #[cfg(feature = "object_assets")]
 pub mod object_assets;
+#[cfg(any(feature = "social_assets", feature = "dev_assets"))]
+pub mod share_link;
 #[cfg(feature = "social_assets")]
 pub mod social_assets;
+#[cfg(any(
+    feature = "browser_assets",
+    feature = "business_assets",
+    feature = "communication_assets",
+    feature = "controller_assets",
+    feature = "dev_assets",
+    feature = "device_assets",
+    feature = "editing_assets",
+    feature = "env_assets",
+    feature = "file_assets",
+    feature = "info_assets",
+    feature = "multimedia_assets",
+    feature = "nav_assets",
+    feature = "object_assets",
+    feature = "social_assets",
+    feature = "ux_assets",
+))]
+pub mod toggle_icon;
 #[cfg(feature = "ux_assets")]
 pub mod ux_assets;
+#[cfg(feature = "env_assets")]
+pub mod weather_icon;
@@ -0,0 +1,144 @@
+use crate::Brand;
+use yew::prelude::*;
+
+pub enum Msg {}
+
+/// # ShareLink
+///
+/// Link with the icon of a brand to share a page. Nothing is rendered for the brands
+/// which can't share a page from an url, like Instagram or GitHub
+///
+/// ## Feature
+/// social_assets or dev_assets
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_assets::share_link::ShareLink;
+/// use yew_assets::Brand;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App {}
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         <ShareLink
+///             network = Brand::Twitter
+///             url = "https://yew.rs"
+///             text = "Yew assets"
+///             brand_color = true
+///         />
+///     }
+/// }
+/// ```
+pub struct ShareLink {
+    pub props: Props,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    /// Brand where the page is shared
+    pub network: Brand,
+    /// Url of the page to share
+    pub url: String,
+    /// Text shared with the url, if the brand supports it
+    #[prop_or_default]
+    pub text: String,
+    /// Show the icon with the official color of the brand
+    #[prop_or_default]
+    pub brand_color: bool,
+    /// Label read by the screen readers. By default "Share on" and the name of the brand
+    #[prop_or_default]
+    pub label: Option<String>,
+    /// Size of the icon
+    #[prop_or(("24".to_string(),"24".to_string()))]
+    pub size: (String, String),
+    /// Fill the color of the icon
+    #[prop_or("none".to_string())]
+    pub fill: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Content shown after the icon, like the name of the brand
+    #[prop_or_default]
+    pub children: Children,
+}
+
+impl Component for ShareLink {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let network = self.props.network;
+        let href = match network.share_url(&self.props.url, &self.props.text) {
+            Some(href) => href,
+            None => return html! {},
+        };
+        let label = match &self.props.label {
+            Some(label) => label.clone(),
+            None => format!("Share on {}", network.name()),
+        };
+        let style = if self.props.brand_color {
+            format!("color: {}", network.color())
+        } else {
+            String::new()
+        };
+        let icon = network.icon().html(
+            self.props.size.clone(),
+            (
+                "0".to_string(),
+                "0".to_string(),
+                "24".to_string(),
+                "24".to_string(),
+            ),
+            self.props.fill.clone(),
+            String::new(),
+            String::new(),
+        );
+
+        html! {
+            <a
+                href=href
+                target="_blank"
+                rel="noopener noreferrer"
+                aria-label=label
+                class=self.props.class_name.clone()
+                id=self.props.id.clone()
+                style=style
+            >
+                {icon}
+                {self.props.children.clone()}
+            </a>
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::Icon;
+use yew::prelude::*;
+
+#[cfg(feature = "communication_assets")]
+use crate::communication_assets::CommunicationIcon;
+#[cfg(feature = "controller_assets")]
+use crate::controller_assets::ControllerIcon;
+#[cfg(feature = "device_assets")]
+use crate::device_assets::DeviceIcon;
+#[cfg(feature = "env_assets")]
+use crate::env_assets::EnvIcon;
+#[cfg(feature = "multimedia_assets")]
+use crate::multimedia_assets::MultimediaIcon;
+#[cfg(feature = "ux_assets")]
+use crate::ux_assets::UxIcon;
+
+pub enum Msg {
+    Toggle,
+}
+
+/// # ToggleIcon
+///
+/// Button which shows one icon of an on/off pair, like eye and eye off,
+/// with `aria-pressed` set to its state. It doesn't keep the state,
+/// `onchange` gets the new state and the parent sets it back in `on`
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_assets::toggle_icon::{IconPair, ToggleIcon};
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+///     visible: bool,
+/// }
+///
+/// impl Component for App {
+///     type Message = bool;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App {
+///             link,
+///             visible: false,
+///         }
+///     }
+///
+///     fn update(&mut self, visible: Self::Message) -> ShouldRender {
+///         self.visible = visible;
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         <ToggleIcon
+///             pair = IconPair::Visibility
+///             on = self.visible
+///             onchange = self.link.callback(|visible| visible)
+///         />
+///     }
+/// }
+/// ```
+pub struct ToggleIcon {
+    pub link: ComponentLink<Self>,
+    pub props: Props,
+}
+
+/// Icons for the on and off states of a toggle
+#[derive(Clone)]
+pub enum IconPair {
+    /// `UxIcon::Eye` and `UxIcon::EyeOff`
+    #[cfg(feature = "ux_assets")]
+    Visibility,
+    /// `UxIcon::Bell` and `UxIcon::BellOff`
+    #[cfg(feature = "ux_assets")]
+    Notifications,
+    /// `UxIcon::Lock` and `UxIcon::Unlock`
+    #[cfg(feature = "ux_assets")]
+    Lock,
+    /// `UxIcon::ToggleRight` and `UxIcon::ToggleLeft`
+    #[cfg(feature = "ux_assets")]
+    Switch,
+    /// `CommunicationIcon::Wifi` and `CommunicationIcon::WifiOff`
+    #[cfg(feature = "communication_assets")]
+    Wifi,
+    /// `CommunicationIcon::Phone` and `CommunicationIcon::PhoneOff`
+    #[cfg(feature = "communication_assets")]
+    Phone,
+    /// `MultimediaIcon::Video` and `MultimediaIcon::VideoOff`
+    #[cfg(feature = "multimedia_assets")]
+    Video,
+    /// `ControllerIcon::Mic` and `ControllerIcon::MicOff`
+    #[cfg(feature = "controller_assets")]
+    Microphone,
+    /// `ControllerIcon::Volume2` and `ControllerIcon::VolumeX`
+    #[cfg(feature = "controller_assets")]
+    Sound,
+    /// `DeviceIcon::Camera` and `DeviceIcon::CameraOff`
+    #[cfg(feature = "device_assets")]
+    Camera,
+    /// `EnvIcon::Cloud` and `EnvIcon::CloudOff`
+    #[cfg(feature = "env_assets")]
+    Cloud,
+    /// Any two icons
+    Custom { on: Icon, off: Icon, label: String },
+}
+
+impl IconPair {
+    /// Pair of any two icons with the label read by the screen readers
+    pub fn custom(on: impl Into<Icon>, off: impl Into<Icon>, label: &str) -> Self {
+        IconPair::Custom {
+            on: on.into(),
+            off: off.into(),
+            label: label.to_string(),
+        }
+    }
+
+    /// Icon of the on state
+    pub fn on(&self) -> Icon {
+        match *self {
+            #[cfg(feature = "ux_assets")]
+            IconPair::Visibility => UxIcon::Eye.into(),
+            #[cfg(feature = "ux_assets")]
+            IconPair::Notifications => UxIcon::Bell.into(),
+            #[cfg(feature = "ux_assets")]
+            IconPair::Lock => UxIcon::Lock.into(),
+            #[cfg(feature = "ux_assets")]
+            IconPair::Switch => UxIcon::ToggleRight.into(),
+            #[cfg(feature = "communication_assets")]
+            IconPair::Wifi => CommunicationIcon::Wifi.into(),
+            #[cfg(feature = "communication_assets")]
+            IconPair::Phone => CommunicationIcon::Phone.into(),
+            #[cfg(feature = "multimedia_assets")]
+            IconPair::Video => MultimediaIcon::Video.into(),
+            #[cfg(feature = "controller_assets")]
+            IconPair::Microphone => ControllerIcon::Mic.into(),
+            #[cfg(feature = "controller_assets")]
+            IconPair::Sound => ControllerIcon::Volume2.into(),
+            #[cfg(feature = "device_assets")]
+            IconPair::Camera => DeviceIcon::Camera.into(),
+            #[cfg(feature = "env_assets")]
+            IconPair::Cloud => EnvIcon::Cloud.into(),
+            IconPair::Custom { ref on, .. } => on.clone(),
+        }
+    }
+
+    /// Icon of the off state
+    pub fn off(&self) -> Icon {
+        match *self {
+            #[cfg(feature = "ux_assets")]
+            IconPair::Visibility => UxIcon::EyeOff.into(),
+            #[cfg(feature = "ux_assets")]
+            IconPair::Notifications => UxIcon::BellOff.into(),
+            #[cfg(feature = "ux_assets")]
+            IconPair::Lock => UxIcon::Unlock.into(),
+            #[cfg(feature = "ux_assets")]
+            IconPair::Switch => UxIcon::ToggleLeft.into(),
+            #[cfg(feature = "communication_assets")]
+            IconPair::Wifi => CommunicationIcon::WifiOff.into(),
+            #[cfg(feature = "communication_assets")]
+            IconPair::Phone => CommunicationIcon::PhoneOff.into(),
+            #[cfg(feature = "multimedia_assets")]
+            IconPair::Video => MultimediaIcon::VideoOff.into(),
+            #[cfg(feature = "controller_assets")]
+            IconPair::Microphone => ControllerIcon::MicOff.into(),
+            #[cfg(feature = "controller_assets")]
+            IconPair::Sound => ControllerIcon::VolumeX.into(),
+            #[cfg(feature = "device_assets")]
+            IconPair::Camera => DeviceIcon::CameraOff.into(),
+            #[cfg(feature = "env_assets")]
+            IconPair::Cloud => EnvIcon::CloudOff.into(),
+            IconPair::Custom { ref off, .. } => off.clone(),
+        }
+    }
+
+    /// Label of the toggle read by the screen readers
+    pub fn label(&self) -> &str {
+        match *self {
+            #[cfg(feature = "ux_assets")]
+            IconPair::Visibility => "Visibility",
+            #[cfg(feature = "ux_assets")]
+            IconPair::Notifications => "Notifications",
+            #[cfg(feature = "ux_assets")]
+            IconPair::Lock => "Lock",
+            #[cfg(feature = "ux_assets")]
+            IconPair::Switch => "Switch",
+            #[cfg(feature = "communication_assets")]
+            IconPair::Wifi => "Wifi",
+            #[cfg(feature = "communication_assets")]
+            IconPair::Phone => "Phone",
+            #[cfg(feature = "multimedia_assets")]
+            IconPair::Video => "Video",
+            #[cfg(feature = "controller_assets")]
+            IconPair::Microphone => "Microphone",
+            #[cfg(feature = "controller_assets")]
+            IconPair::Sound => "Sound",
+            #[cfg(feature = "device_assets")]
+            IconPair::Camera => "Camera",
+            #[cfg(feature = "env_assets")]
+            IconPair::Cloud => "Cloud",
+            IconPair::Custom { ref label, .. } => label.as_str(),
+        }
+    }
+
+    /// Icon of the state
+    pub fn icon(&self, on: bool) -> Icon {
+        if on {
+            self.on()
+        } else {
+            self.off()
+        }
+    }
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    /// Icons of the on and off states
+    pub pair: IconPair,
+    /// State of the toggle
+    #[prop_or_default]
+    pub on: bool,
+    /// Called with the new state when the toggle is pressed
+    #[prop_or_default]
+    pub onchange: Callback<bool>,
+    /// Label read by the screen readers. By default the label of the pair
+    #[prop_or_default]
+    pub label: Option<String>,
+    /// Disable the toggle
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Size of the icons
+    #[prop_or(("24".to_string(),"24".to_string()))]
+    pub size: (String, String),
+    /// Defines the position and dimension of the icons
+    #[prop_or(("0".to_string(),"0".to_string(),"24".to_string(),"24".to_string()))]
+    pub view_box: (String, String, String, String),
+    /// Fill the color of the icons
+    #[prop_or("none".to_string())]
+    pub fill: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+}
+
+impl Component for ToggleIcon {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => self.props.onchange.emit(!self.props.on),
+        }
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let label = match &self.props.label {
+            Some(label) => label.clone(),
+            None => self.props.pair.label().to_string(),
+        };
+        let icon = self.props.pair.icon(self.props.on).html(
+            self.props.size.clone(),
+            self.props.view_box.clone(),
+            self.props.fill.clone(),
+            String::new(),
+            String::new(),
+        );
+
+        html! {
+            <button
+                type="button"
+                class=self.props.class_name.clone()
+                id=self.props.id.clone()
+                aria-pressed=self.props.on.to_string()
+                aria-label=label
+                disabled=self.props.disabled
+                onclick=self.link.callback(|_| Msg::Toggle)
+            >
+                {icon}
+            </button>
+        }
+    }
+}
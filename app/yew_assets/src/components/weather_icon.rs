@@ -0,0 +1,130 @@
+use crate::env_assets::get_icon;
+use crate::WeatherCondition;
+use yew::prelude::*;
+use yew::virtual_dom::VNode;
+
+pub enum Msg {}
+
+/// # WeatherIcon
+///
+/// Add the svg EnvIcon of a weather condition, with the sun or the moon by the time of the day
+///
+/// ## Feature
+/// env_assets
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_assets::weather_icon::WeatherIcon;
+/// use yew_assets::WeatherCondition;
+///
+/// pub struct App {
+///     weather: OpenWeather,
+/// }
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App { weather: get_weather() }
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         <WeatherIcon
+///             condition = WeatherCondition::from_openweather_id(self.weather.id).unwrap()
+///             timestamp = self.weather.dt
+///             sunrise = self.weather.sunrise
+///             sunset = self.weather.sunset
+///         />
+///     }
+/// }
+/// ```
+pub struct WeatherIcon {
+    pub props: Props,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    /// Weather condition to show
+    pub condition: WeatherCondition,
+    /// Show the sun instead of the moon, if the timestamp, the sunrise and the sunset are not set
+    #[prop_or(true)]
+    pub daytime: bool,
+    /// Unix timestamp in seconds of the weather
+    #[prop_or_default]
+    pub timestamp: Option<i64>,
+    /// Unix timestamp in seconds of the sunrise of the day
+    #[prop_or_default]
+    pub sunrise: Option<i64>,
+    /// Unix timestamp in seconds of the sunset of the day
+    #[prop_or_default]
+    pub sunset: Option<i64>,
+    /// Size of the WeatherIcon
+    #[prop_or(("24".to_string(),"24".to_string()))]
+    pub size: (String, String),
+    /// Defines the position and dimension of the WeatherIcon
+    #[prop_or(("0".to_string(),"0".to_string(),"24".to_string(),"24".to_string()))]
+    pub view_box: (String, String, String, String),
+    /// Fill the color of the asset
+    #[prop_or("none".to_string())]
+    pub fill: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+}
+
+impl Component for WeatherIcon {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let condition = self.props.condition;
+        let icon = match (self.props.timestamp, self.props.sunrise, self.props.sunset) {
+            (Some(timestamp), Some(sunrise), Some(sunset)) => {
+                condition.icon_at(timestamp, sunrise, sunset)
+            }
+            _ => condition.icon(self.props.daytime),
+        };
+
+        match get_icon(
+            icon,
+            self.props.size.clone(),
+            self.props.view_box.clone(),
+            self.props.fill.clone(),
+            self.props.class_name.clone(),
+            self.props.id.clone(),
+        ) {
+            VNode::VTag(mut tag) => {
+                tag.add_attribute("role", "img");
+                tag.add_attribute("aria-label", condition.description());
+                VNode::VTag(tag)
+            }
+            icon => icon,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::cell::Cell;
+use std::collections::HashSet;
+use yew::agent::{Agent, AgentLink, Context, Dispatched, HandlerId};
+
+thread_local! {
+    static RTL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// # LayoutDirection
+///
+/// Context agent with the direction of the layout for all the `*Assets` components
+///
+/// Every component is bridged to it and renders again when the direction changes,
+/// so the direction-sensitive icons already mounted, like `ControllerIcon::ArrowLeft`
+/// or `NavIcon::Send`, are mirrored when the locale switches to a right-to-left one.
+/// The input is `true` for right-to-left layouts and every bridge gets the current
+/// direction when it connects and after each change. The `rtl` property of each
+/// component takes precedence over it.
+///
+/// ## Example
+/// ```rust,no_run
+/// use yew::agent::Dispatched;
+/// use yew_assets::LayoutDirection;
+///
+/// let locale = "ar";
+/// LayoutDirection::dispatcher().send(locale == "ar" || locale == "he");
+/// ```
+pub struct LayoutDirection {
+    link: AgentLink<Self>,
+    subscribers: HashSet<HandlerId>,
+}
+
+impl Agent for LayoutDirection {
+    type Reach = Context<Self>;
+    type Message = ();
+    type Input = bool;
+    type Output = bool;
+
+    fn create(link: AgentLink<Self>) -> Self {
+        LayoutDirection {
+            link,
+            subscribers: HashSet::new(),
+        }
+    }
+
+    fn update(&mut self, _msg: Self::Message) {}
+
+    fn connected(&mut self, id: HandlerId) {
+        // the dispatchers only send the direction
+        if id.is_respondable() {
+            self.subscribers.insert(id);
+            self.link.respond(id, is_rtl());
+        }
+    }
+
+    fn handle_input(&mut self, rtl: bool, _id: HandlerId) {
+        // kept out of the agent, which is destroyed when the last bridge is dropped
+        if RTL.with(|value| value.replace(rtl)) == rtl {
+            return;
+        }
+
+        for id in self.subscribers.iter() {
+            self.link.respond(*id, rtl);
+        }
+    }
+
+    fn disconnected(&mut self, id: HandlerId) {
+        self.subscribers.remove(&id);
+    }
+}
+
+/// Set the direction of the layout through the `LayoutDirection` context,
+/// rendering again the mounted `*Assets` components
+///
+/// ## Example
+/// ```rust,no_run
+/// use yew_assets::set_rtl;
+///
+/// let locale = "ar";
+/// set_rtl(locale == "ar" || locale == "he");
+/// ```
+pub fn set_rtl(rtl: bool) {
+    LayoutDirection::dispatcher().send(rtl);
+}
+
+/// Whether the layout is right-to-left for all the `*Assets` components
+pub fn is_rtl() -> bool {
+    RTL.with(|value| value.get())
+}
+
+/// Mirror horizontally the icon if it is directional and the layout is right-to-left
+#[cfg(any(
+    feature = "browser_assets",
+    feature = "business_assets",
+    feature = "communication_assets",
+    feature = "controller_assets",
+    feature = "dev_assets",
+    feature = "device_assets",
+    feature = "editing_assets",
+    feature = "env_assets",
+    feature = "file_assets",
+    feature = "info_assets",
+    feature = "multimedia_assets",
+    feature = "nav_assets",
+    feature = "object_assets",
+    feature = "social_assets",
+    feature = "ux_assets",
+))]
+pub(crate) fn get_directional_icon(icon: yew::Html, directional: bool, rtl: bool) -> yew::Html {
+    use yew::virtual_dom::VNode;
+
+    match icon {
+        VNode::VTag(mut tag) if directional && rtl => {
+            tag.add_attribute("style", "transform: scaleX(-1)");
+            VNode::VTag(tag)
+        }
+        icon => icon,
+    }
+}
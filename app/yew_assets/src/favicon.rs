@@ -0,0 +1,195 @@
+use crate::raster::svg_to_png;
+use crate::svg::escape;
+use crate::Icon;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Sizes of the pngs included in the favicon.ico
+const ICO_SIZES: [u32; 3] = [16, 32, 48];
+/// Sizes of the pngs files with its file name
+const PNG_FILES: [(u32, &str); 5] = [
+    (16, "favicon-16x16.png"),
+    (32, "favicon-32x32.png"),
+    (180, "apple-touch-icon.png"),
+    (192, "icon-192.png"),
+    (512, "icon-512.png"),
+];
+/// Sizes of the maskable pngs files with its file name
+const MASKABLE_FILES: [(u32, &str); 2] = [
+    (192, "icon-192-maskable.png"),
+    (512, "icon-512-maskable.png"),
+];
+/// Minimum padding of the maskable icons to keep them inside the safe zone,
+/// a circle with 40% of radius in the center of the icon
+const MASKABLE_PADDING: f32 = 0.22;
+
+/// # Favicon
+///
+/// Generate a complete favicon set from any icon: `favicon.ico`, the 16, 32, 180, 192 and 512 pngs,
+/// the maskable variants and the icons of the `manifest.json`
+///
+/// ## Feature
+/// raster
+///
+/// ## Example
+/// ```rust,no_run
+/// use yew_assets::Favicon;
+/// use yew_assets::dev_assets::DevIcon;
+///
+/// let mut favicon = Favicon::new(DevIcon::Terminal);
+/// favicon.foreground = "#fff".to_string();
+/// favicon.background = "#1e1e1e".to_string();
+/// favicon.corner_radius = 0.2;
+///
+/// favicon.write("static/favicon").unwrap();
+/// println!("{}", favicon.manifest_icons("/static/favicon"));
+/// ```
+#[derive(Clone)]
+pub struct Favicon {
+    /// Icon to show in the favicon
+    pub icon: Icon,
+    /// Stroke color of the icon
+    pub foreground: String,
+    /// Background color of the favicon, `"none"` for transparent
+    pub background: String,
+    /// Space between the icon and the border as a fraction of the size
+    pub padding: f32,
+    /// Radius of the corners of the background as a fraction of the size
+    pub corner_radius: f32,
+}
+
+impl Favicon {
+    pub fn new(icon: impl Into<Icon>) -> Self {
+        Favicon {
+            icon: icon.into(),
+            foreground: "#000".to_string(),
+            background: "#fff".to_string(),
+            padding: 0.125,
+            corner_radius: 0.0,
+        }
+    }
+
+    /// Get the svg of the favicon with the background, padding and corner radius
+    ///
+    /// Maskable icons get a full bleed background and at least the padding
+    /// needed to keep the icon inside the safe zone
+    pub fn to_svg(&self, size: u32, maskable: bool) -> String {
+        let size = size as f32;
+        let (padding, corner_radius) = if maskable {
+            (self.padding.max(MASKABLE_PADDING), 0.0)
+        } else {
+            (self.padding, self.corner_radius)
+        };
+        let offset = size * padding;
+        let icon_size = size - offset * 2.0;
+        let icon = self
+            .icon
+            .to_svg(&icon_size.to_string(), &self.foreground)
+            .replacen(
+                "<svg",
+                &format!("<svg x=\"{}\" y=\"{}\"", offset, offset),
+                1,
+            );
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size}\" height=\"{size}\" viewBox=\"0 0 {size} {size}\"><rect width=\"{size}\" height=\"{size}\" rx=\"{radius}\" fill=\"{background}\"></rect>{icon}</svg>",
+            size = size,
+            radius = size * corner_radius,
+            background = escape(&self.background),
+            icon = icon,
+        )
+    }
+
+    /// Rasterise the favicon to png
+    pub fn render_png(&self, size: u32, maskable: bool) -> Vec<u8> {
+        svg_to_png(&self.to_svg(size, maskable), size)
+    }
+
+    /// Get the favicon.ico with the 16, 32 and 48 pngs
+    pub fn render_ico(&self) -> Vec<u8> {
+        let images: Vec<(u32, Vec<u8>)> = ICO_SIZES
+            .iter()
+            .map(|size| (*size, self.render_png(*size, false)))
+            .collect();
+
+        let mut ico: Vec<u8> = vec![];
+        // reserved, type (1 = icon) and number of images
+        ico.extend(&0u16.to_le_bytes());
+        ico.extend(&1u16.to_le_bytes());
+        ico.extend(&(images.len() as u16).to_le_bytes());
+
+        let mut offset = 6 + 16 * images.len() as u32;
+
+        for (size, png) in images.iter() {
+            // width and height, 0 means 256
+            ico.push(*size as u8);
+            ico.push(*size as u8);
+            // colors in the palette and reserved
+            ico.push(0);
+            ico.push(0);
+            // color planes and bits per pixel
+            ico.extend(&1u16.to_le_bytes());
+            ico.extend(&32u16.to_le_bytes());
+            ico.extend(&(png.len() as u32).to_le_bytes());
+            ico.extend(&offset.to_le_bytes());
+
+            offset += png.len() as u32;
+        }
+
+        for (_, png) in images {
+            ico.extend(png);
+        }
+
+        ico
+    }
+
+    /// Get all the files of the favicon set with its file name
+    pub fn files(&self) -> Vec<(String, Vec<u8>)> {
+        let mut files = vec![("favicon.ico".to_string(), self.render_ico())];
+
+        for (size, name) in PNG_FILES.iter() {
+            files.push((name.to_string(), self.render_png(*size, false)));
+        }
+
+        for (size, name) in MASKABLE_FILES.iter() {
+            files.push((name.to_string(), self.render_png(*size, true)));
+        }
+
+        files
+    }
+
+    /// Write all the files of the favicon set in the directory
+    pub fn write<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        fs::create_dir_all(&dir)?;
+
+        for (name, content) in self.files() {
+            fs::write(dir.as_ref().join(name), content)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the `icons` member of the `manifest.json` with the files served from `path`
+    pub fn manifest_icons(&self, path: &str) -> String {
+        let path = path.trim_end_matches('/');
+        let icons: Vec<String> = PNG_FILES
+            .iter()
+            .filter(|(size, _)| *size >= 192)
+            .map(|(size, name)| (size, name, "any"))
+            .chain(
+                MASKABLE_FILES
+                    .iter()
+                    .map(|(size, name)| (size, name, "maskable")),
+            )
+            .map(|(size, name, purpose)| {
+                format!(
+                    "    {{ \"src\": \"{}/{}\", \"sizes\": \"{}x{}\", \"type\": \"image/png\", \"purpose\": \"{}\" }}",
+                    path, name, size, size, purpose
+                )
+            })
+            .collect();
+
+        format!("\"icons\": [\n{}\n]", icons.join(",\n"))
+    }
+}
@@ -0,0 +1,393 @@
+use crate::file_assets::FileIcon;
+use crate::Icon;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[cfg(feature = "dev_assets")]
+use crate::dev_assets::DevIcon;
+#[cfg(feature = "multimedia_assets")]
+use crate::multimedia_assets::MultimediaIcon;
+#[cfg(feature = "object_assets")]
+use crate::object_assets::ObjectIcon;
+#[cfg(feature = "ux_assets")]
+use crate::ux_assets::UxIcon;
+
+/// Top level MIME types, to tell apart `image/png` from a path like `images/logo.png`
+const MIME_TYPES: [&str; 11] = [
+    "application",
+    "audio",
+    "font",
+    "image",
+    "inode",
+    "message",
+    "model",
+    "multipart",
+    "text",
+    "video",
+    "x-content",
+];
+
+thread_local! {
+    static DEFAULT_TABLE: RefCell<FileIconTable> = RefCell::new(FileIconTable::default());
+}
+
+/// Get the icon which matches best a MIME type, like `image/png`, or a file path, like `report.pdf`
+///
+/// The paths ending with `/` are folders and the unknown types get `FileIcon::File`.
+/// Use `set_file_icon_table` or `update_file_icon_table` to change the icon of some types
+///
+/// ## Feature
+/// file_assets
+///
+/// ## Example
+/// ```rust
+/// use yew_assets::file_icon_for;
+///
+/// let icon = file_icon_for("report.pdf");
+/// assert_eq!(icon.name(), "file-text");
+/// ```
+pub fn file_icon_for(mime_or_path: &str) -> Icon {
+    DEFAULT_TABLE.with(|table| table.borrow().icon_for(mime_or_path))
+}
+
+/// Replace the table used by `file_icon_for` and the `FileTypeIcon` components without a `table`
+///
+/// ## Feature
+/// file_assets
+///
+/// ## Example
+/// ```rust
+/// use yew_assets::file_assets::FileIcon;
+/// use yew_assets::{file_icon_for, set_file_icon_table, FileIconTable};
+///
+/// let mut table = FileIconTable::empty();
+/// table.insert_extension("pdf", FileIcon::FilePlus);
+/// set_file_icon_table(table);
+///
+/// assert_eq!(file_icon_for("report.pdf").name(), "file-plus");
+/// assert_eq!(file_icon_for("logo.png").name(), "file");
+/// ```
+pub fn set_file_icon_table(table: FileIconTable) {
+    DEFAULT_TABLE.with(|default| *default.borrow_mut() = table);
+}
+
+/// Change the table used by `file_icon_for` and the `FileTypeIcon` components without a `table`,
+/// to add types or override the icon of some of them
+///
+/// ## Feature
+/// file_assets
+///
+/// ## Example
+/// ```rust
+/// use yew_assets::file_assets::FileIcon;
+/// use yew_assets::{file_icon_for, update_file_icon_table};
+///
+/// update_file_icon_table(|table| {
+///     table.insert_extension("log", FileIcon::FileMinus);
+///     table.insert_mime_type("application/vnd.acme+json", FileIcon::FilePlus);
+/// });
+///
+/// assert_eq!(file_icon_for("server.log").name(), "file-minus");
+/// assert_eq!(file_icon_for("report.pdf").name(), "file-text");
+/// ```
+pub fn update_file_icon_table(update: impl FnOnce(&mut FileIconTable)) {
+    DEFAULT_TABLE.with(|table| update(&mut table.borrow_mut()));
+}
+
+/// # FileIconTable
+///
+/// Lookup table of the icons by MIME type and by extension used by `file_icon_for`.
+/// It can be given to a `FileTypeIcon`, or replace the table of `file_icon_for` with `set_file_icon_table`.
+/// The default table covers the common documents, images, audio, video, code and archives,
+/// using the icons of the enabled features
+///
+/// ## Feature
+/// file_assets
+///
+/// ## Example
+/// ```rust
+/// use yew_assets::FileIconTable;
+/// use yew_assets::file_assets::FileIcon;
+///
+/// let mut table = FileIconTable::default();
+/// table.insert_extension("log", FileIcon::FileMinus);
+/// table.insert_mime_type("application/vnd.acme+json", FileIcon::FilePlus);
+///
+/// assert_eq!(table.icon_for("server.log").name(), "file-minus");
+/// ```
+#[derive(Clone)]
+pub struct FileIconTable {
+    /// Icons by lowercase extension without the dot, or by lowercase file name like `dockerfile`
+    pub extensions: HashMap<String, Icon>,
+    /// Icons by MIME type, with `type/*` for all the subtypes of a type
+    pub mime_types: HashMap<String, Icon>,
+    /// Icon of the files without a match
+    pub file: Icon,
+    /// Icon of the folders
+    pub folder: Icon,
+}
+
+impl FileIconTable {
+    /// Table without any type, every file gets `FileIcon::File`
+    pub fn empty() -> Self {
+        FileIconTable {
+            extensions: HashMap::new(),
+            mime_types: HashMap::new(),
+            file: FileIcon::File.into(),
+            folder: FileIcon::Folder.into(),
+        }
+    }
+
+    /// Set the icon of an extension, like `pdf`, or of a file name, like `Makefile`
+    pub fn insert_extension(&mut self, extension: &str, icon: impl Into<Icon>) {
+        self.extensions.insert(
+            extension.trim_start_matches('.').to_lowercase(),
+            icon.into(),
+        );
+    }
+
+    /// Set the icon of a MIME type, like `application/pdf`, or of all its subtypes, like `image/*`
+    pub fn insert_mime_type(&mut self, mime_type: &str, icon: impl Into<Icon>) {
+        self.mime_types
+            .insert(mime_type.to_lowercase(), icon.into());
+    }
+
+    /// Get the icon which matches best a MIME type or a file path
+    pub fn icon_for(&self, mime_or_path: &str) -> Icon {
+        let value = mime_or_path.trim();
+
+        if value.ends_with('/') || value.ends_with('\\') {
+            return self.folder.clone();
+        }
+
+        let mime_type = get_mime_type(value);
+
+        if let Some(mime_type) = &mime_type {
+            if mime_type == "inode/directory" {
+                return self.folder.clone();
+            }
+
+            if let Some(icon) = self.mime_types.get(mime_type) {
+                return icon.clone();
+            }
+        }
+
+        // unknown MIME types can still be paths with an extension, like `text/main.rs`,
+        // so the extensions are looked up before the `type/*` wildcards
+        if mime_type.is_none() || value.contains('.') {
+            if let Some(icon) = self.get_extension_icon(value) {
+                return icon.clone();
+            }
+        }
+
+        mime_type
+            .and_then(|mime_type| {
+                let main_type = mime_type.split('/').next().unwrap_or_default();
+                self.mime_types.get(&format!("{}/*", main_type))
+            })
+            .unwrap_or(&self.file)
+            .clone()
+    }
+
+    /// Icon of the file name or the extension of a path
+    fn get_extension_icon(&self, path: &str) -> Option<&Icon> {
+        let name = path
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or(path)
+            .to_lowercase();
+        let extension = match name.rfind('.') {
+            Some(index) if index > 0 => &name[index + 1..],
+            _ => "",
+        };
+
+        self.extensions
+            .get(&name)
+            .or_else(|| self.extensions.get(extension))
+    }
+
+    fn insert_extensions(&mut self, extensions: &[&str], icon: impl Into<Icon>) {
+        let icon = icon.into();
+
+        for extension in extensions {
+            self.insert_extension(extension, icon.clone());
+        }
+    }
+
+    fn insert_mime_types(&mut self, mime_types: &[&str], icon: impl Into<Icon>) {
+        let icon = icon.into();
+
+        for mime_type in mime_types {
+            self.insert_mime_type(mime_type, icon.clone());
+        }
+    }
+}
+
+impl Default for FileIconTable {
+    fn default() -> Self {
+        let mut table = FileIconTable::empty();
+
+        table.insert_mime_types(&["image/*"], FileIcon::Image);
+        table.insert_extensions(
+            &[
+                "png", "jpg", "jpeg", "gif", "svg", "webp", "bmp", "ico", "tif", "tiff", "avif",
+                "heic",
+            ],
+            FileIcon::Image,
+        );
+
+        table.insert_mime_types(
+            &[
+                "text/*",
+                "application/pdf",
+                "application/rtf",
+                "application/msword",
+                "application/vnd.oasis.opendocument.text",
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            ],
+            FileIcon::FileText,
+        );
+        // the spreadsheets get `UxIcon::Grid` when the ux_assets feature is enabled
+        table.insert_extensions(
+            &[
+                "txt", "md", "markdown", "rst", "log", "pdf", "rtf", "doc", "docx", "odt", "csv",
+                "xls", "xlsx", "ods",
+            ],
+            FileIcon::FileText,
+        );
+
+        #[cfg(feature = "multimedia_assets")]
+        {
+            table.insert_mime_types(&["video/*"], MultimediaIcon::Film);
+            table.insert_extensions(
+                &[
+                    "mp4", "m4v", "mkv", "mov", "avi", "webm", "wmv", "flv", "mpg", "mpeg",
+                ],
+                MultimediaIcon::Film,
+            );
+            table.insert_mime_types(&["audio/*"], MultimediaIcon::Music);
+            table.insert_extensions(
+                &[
+                    "mp3", "wav", "flac", "ogg", "oga", "m4a", "aac", "opus", "mid", "midi",
+                ],
+                MultimediaIcon::Music,
+            );
+        }
+
+        #[cfg(feature = "dev_assets")]
+        {
+            table.insert_mime_types(
+                &[
+                    "text/html",
+                    "text/css",
+                    "text/javascript",
+                    "application/javascript",
+                    "application/json",
+                    "application/xml",
+                    "text/xml",
+                    "application/wasm",
+                    "application/toml",
+                    "application/yaml",
+                ],
+                DevIcon::Code,
+            );
+            table.insert_extensions(
+                &[
+                    "rs", "js", "mjs", "ts", "jsx", "tsx", "py", "rb", "go", "java", "kt", "c",
+                    "h", "cpp", "hpp", "cs", "php", "swift", "html", "htm", "css", "scss", "sass",
+                    "json", "toml", "yaml", "yml", "xml", "wasm", "vue",
+                ],
+                DevIcon::Code,
+            );
+            table.insert_mime_types(
+                &["application/x-sh", "application/x-shellscript"],
+                DevIcon::Terminal,
+            );
+            table.insert_extensions(
+                &[
+                    "sh",
+                    "bash",
+                    "zsh",
+                    "fish",
+                    "ps1",
+                    "bat",
+                    "cmd",
+                    "makefile",
+                    "dockerfile",
+                ],
+                DevIcon::Terminal,
+            );
+            table.insert_mime_types(
+                &["application/sql", "application/vnd.sqlite3"],
+                DevIcon::Database,
+            );
+            table.insert_extensions(&["sql", "db", "sqlite", "sqlite3"], DevIcon::Database);
+        }
+
+        #[cfg(feature = "ux_assets")]
+        {
+            table.insert_mime_types(
+                &[
+                    "application/zip",
+                    "application/gzip",
+                    "application/x-tar",
+                    "application/x-bzip2",
+                    "application/x-xz",
+                    "application/x-7z-compressed",
+                    "application/vnd.rar",
+                ],
+                UxIcon::Archive,
+            );
+            table.insert_extensions(
+                &["zip", "tar", "gz", "tgz", "bz2", "xz", "7z", "rar"],
+                UxIcon::Archive,
+            );
+            table.insert_mime_types(
+                &[
+                    "text/csv",
+                    "application/vnd.ms-excel",
+                    "application/vnd.oasis.opendocument.spreadsheet",
+                    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+                ],
+                UxIcon::Grid,
+            );
+            table.insert_extensions(&["csv", "xls", "xlsx", "ods"], UxIcon::Grid);
+        }
+
+        #[cfg(feature = "object_assets")]
+        {
+            table.insert_mime_types(
+                &[
+                    "application/vnd.debian.binary-package",
+                    "application/x-rpm",
+                    "application/vnd.android.package-archive",
+                    "application/java-archive",
+                ],
+                ObjectIcon::Package,
+            );
+            table.insert_extensions(
+                &["deb", "rpm", "apk", "dmg", "pkg", "msi", "jar", "crate"],
+                ObjectIcon::Package,
+            );
+            table.insert_mime_types(&["application/epub+zip"], ObjectIcon::Book);
+            table.insert_extensions(&["epub", "mobi"], ObjectIcon::Book);
+        }
+
+        table
+    }
+}
+
+/// Get the MIME type without parameters if the value is a MIME type and not a path
+fn get_mime_type(value: &str) -> Option<String> {
+    let mime_type = value.split(';').next().unwrap_or_default().trim();
+    let (main_type, subtype) = mime_type.split_once('/')?;
+
+    if MIME_TYPES.contains(&main_type.to_lowercase().as_str())
+        && !subtype.is_empty()
+        && !subtype.contains(|c: char| c == '/' || c == '\\' || c.is_whitespace())
+    {
+        Some(mime_type.to_lowercase())
+    } else {
+        None
+    }
+}
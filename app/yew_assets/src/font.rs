@@ -0,0 +1,546 @@
+use crate::{Icon, IconCategory};
+use brotli::CompressorWriter;
+use kurbo::{BezPath, CubicBez, Point};
+use resvg::tiny_skia::{Path, PathSegment, Transform};
+use resvg::usvg::{Group, Node, Options, Tree};
+use std::collections::HashMap;
+use std::io::Write;
+use write_fonts::tables::cmap::Cmap;
+use write_fonts::tables::glyf::{GlyfLocaBuilder, Glyph, SimpleGlyph};
+use write_fonts::tables::head::Head;
+use write_fonts::tables::hhea::Hhea;
+use write_fonts::tables::hmtx::{Hmtx, LongMetric};
+use write_fonts::tables::maxp::Maxp;
+use write_fonts::tables::name::{Name, NameRecord};
+use write_fonts::tables::os2::Os2;
+use write_fonts::tables::post::Post;
+use write_fonts::types::{FWord, GlyphId, NameId, UfWord};
+use write_fonts::{FontBuilder, OffsetMarker};
+
+/// Units per em of the font, the 24 units of the icons view box scaled by 40
+const UNITS_PER_EM: u16 = 960;
+const SCALE: f64 = 40.0;
+/// Icons are moved down under the baseline like the descenders of the text
+const DESCENDER: i16 = -120;
+const ASCENDER: i16 = UNITS_PER_EM as i16 + DESCENDER;
+/// Maximum distance between the cubic curves of the strokes and the quadratic curves of the glyphs
+const CURVE_ACCURACY: f64 = 0.5;
+/// First codepoint of the private use area of unicode
+const FIRST_CODEPOINT: u32 = 0xE000;
+
+/// Tables which have a known tag index in the woff2 table directory
+const WOFF2_KNOWN_TAGS: [&[u8; 4]; 12] = [
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm",
+    b"glyf", b"loca",
+];
+
+impl Icon {
+    /// Codepoint of the icon in the icon font
+    ///
+    /// Codepoints are stable: every category gets a block of 256 codepoints
+    /// in the private use area and every icon the position inside its category,
+    /// so they don't change with the selected features or when new icons are added
+    /// at the end of a category
+    pub fn codepoint(&self) -> char {
+        let category = self.category();
+        let icon_index = category
+            .icons()
+            .iter()
+            .position(|icon| icon.name() == self.name())
+            .unwrap_or_default();
+
+        get_codepoint(category, icon_index)
+    }
+}
+
+fn get_codepoint(category: IconCategory, icon_index: usize) -> char {
+    std::char::from_u32(FIRST_CODEPOINT + get_block(category) * 0x100 + icon_index as u32)
+        .expect("codepoint out of the private use area")
+}
+
+/// Index of the block of codepoints of the category
+fn get_block(category: IconCategory) -> u32 {
+    match category {
+        #[cfg(feature = "browser_assets")]
+        IconCategory::Browser => 0,
+        #[cfg(feature = "business_assets")]
+        IconCategory::Business => 1,
+        #[cfg(feature = "communication_assets")]
+        IconCategory::Communication => 2,
+        #[cfg(feature = "controller_assets")]
+        IconCategory::Controller => 3,
+        #[cfg(feature = "dev_assets")]
+        IconCategory::Dev => 4,
+        #[cfg(feature = "device_assets")]
+        IconCategory::Device => 5,
+        #[cfg(feature = "editing_assets")]
+        IconCategory::Editing => 6,
+        #[cfg(feature = "env_assets")]
+        IconCategory::Env => 7,
+        #[cfg(feature = "file_assets")]
+        IconCategory::File => 8,
+        #[cfg(feature = "info_assets")]
+        IconCategory::Info => 9,
+        #[cfg(feature = "multimedia_assets")]
+        IconCategory::Multimedia => 10,
+        #[cfg(feature = "nav_assets")]
+        IconCategory::Nav => 11,
+        #[cfg(feature = "object_assets")]
+        IconCategory::Object => 12,
+        #[cfg(feature = "social_assets")]
+        IconCategory::Social => 13,
+        #[cfg(feature = "ux_assets")]
+        IconCategory::Ux => 14,
+    }
+}
+
+/// # IconFont
+///
+/// Compile icons into a font with a css file, so the same icons can be used
+/// in plain html pages with classes like `feather-shield-off`
+///
+/// ## Feature
+/// font
+///
+/// ## Example
+/// ```rust,no_run
+/// use yew_assets::{Icon, IconFont};
+///
+/// let font = IconFont::new(Icon::all());
+///
+/// std::fs::write("feather.woff2", font.to_woff2()).unwrap();
+/// std::fs::write("feather.css", font.to_css("feather.woff2")).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct IconFont {
+    /// Icons included in the font
+    pub icons: Vec<Icon>,
+    /// Name of the font family
+    pub family: String,
+    /// Prefix of the css classes
+    pub prefix: String,
+}
+
+impl IconFont {
+    pub fn new(icons: Vec<Icon>) -> Self {
+        IconFont {
+            icons,
+            family: "feather".to_string(),
+            prefix: "feather".to_string(),
+        }
+    }
+
+    /// Codepoints of the icons, looking up the positions of every category only once
+    fn codepoints(&self) -> Vec<char> {
+        let mut positions: HashMap<IconCategory, HashMap<&'static str, usize>> = HashMap::new();
+
+        self.icons
+            .iter()
+            .map(|icon| {
+                let category = icon.category();
+                let icon_index = positions
+                    .entry(category)
+                    .or_insert_with(|| {
+                        category
+                            .icons()
+                            .iter()
+                            .enumerate()
+                            .map(|(index, icon)| (icon.name(), index))
+                            .collect()
+                    })
+                    .get(icon.name())
+                    .copied()
+                    .unwrap_or_default();
+
+                get_codepoint(category, icon_index)
+            })
+            .collect()
+    }
+
+    /// Get the font in TrueType format
+    pub fn to_ttf(&self) -> Vec<u8> {
+        let mut glyphs: Vec<Glyph> = vec![Glyph::Empty];
+        let mut mappings: Vec<(char, GlyphId)> = vec![];
+
+        for (icon, codepoint) in self.icons.iter().zip(self.codepoints()) {
+            mappings.push((codepoint, GlyphId::new(glyphs.len() as u32)));
+            glyphs.push(get_glyph(icon));
+        }
+
+        let mut glyf_loca = GlyfLocaBuilder::new();
+        let mut metrics: Vec<LongMetric> = vec![];
+        let mut bbox = (0, 0, 0, 0);
+        let mut max_points = 0;
+        let mut max_contours = 0;
+
+        for glyph in glyphs.iter() {
+            glyf_loca
+                .add_glyph(glyph)
+                .expect("invalid glyph of the icon font");
+
+            let x_min = if let Glyph::Simple(simple) = glyph {
+                bbox = (
+                    bbox.0.min(simple.bbox.x_min),
+                    bbox.1.min(simple.bbox.y_min),
+                    bbox.2.max(simple.bbox.x_max),
+                    bbox.3.max(simple.bbox.y_max),
+                );
+                max_points = max_points.max(simple.contours.iter().map(|c| c.len()).sum());
+                max_contours = max_contours.max(simple.contours.len());
+                simple.bbox.x_min
+            } else {
+                0
+            };
+
+            metrics.push(LongMetric::new(UNITS_PER_EM, x_min));
+        }
+
+        let (glyf, loca, loca_format) = glyf_loca.build();
+
+        let head = Head {
+            units_per_em: UNITS_PER_EM,
+            x_min: bbox.0,
+            y_min: bbox.1,
+            x_max: bbox.2,
+            y_max: bbox.3,
+            lowest_rec_ppem: 8,
+            index_to_loc_format: loca_format as i16,
+            ..Default::default()
+        };
+        let hhea = Hhea::new(
+            FWord::new(ASCENDER),
+            FWord::new(DESCENDER),
+            FWord::new(0),
+            UfWord::new(UNITS_PER_EM),
+            FWord::new(bbox.0),
+            FWord::new(UNITS_PER_EM as i16 - bbox.2),
+            FWord::new(bbox.2),
+            1,
+            0,
+            0,
+            metrics.len() as u16,
+        );
+        let maxp = Maxp {
+            num_glyphs: glyphs.len() as u16,
+            max_points: Some(max_points as u16),
+            max_contours: Some(max_contours as u16),
+            max_composite_points: Some(0),
+            max_composite_contours: Some(0),
+            max_zones: Some(2),
+            max_twilight_points: Some(0),
+            max_storage: Some(0),
+            max_function_defs: Some(0),
+            max_instruction_defs: Some(0),
+            max_stack_elements: Some(0),
+            max_size_of_instructions: Some(0),
+            max_component_elements: Some(0),
+            max_component_depth: Some(0),
+        };
+        let first_char = mappings.iter().map(|(c, _)| *c as u16).min();
+        let last_char = mappings.iter().map(|(c, _)| *c as u16).max();
+        let os2 = Os2 {
+            x_avg_char_width: UNITS_PER_EM as i16,
+            us_first_char_index: first_char.unwrap_or_default(),
+            us_last_char_index: last_char.unwrap_or_default(),
+            s_typo_ascender: ASCENDER,
+            s_typo_descender: DESCENDER,
+            us_win_ascent: ASCENDER as u16,
+            us_win_descent: -DESCENDER as u16,
+            ul_code_page_range_1: Some(1),
+            ul_code_page_range_2: Some(0),
+            sx_height: Some(0),
+            s_cap_height: Some(0),
+            us_default_char: Some(0),
+            us_break_char: Some(0x20),
+            us_max_context: Some(0),
+            ..Default::default()
+        };
+        let cmap = Cmap::from_mappings(mappings).expect("duplicated codepoints in the icon font");
+        let hmtx = Hmtx::new(metrics, vec![]);
+        let name = get_name(&self.family);
+        let post = Post {
+            is_fixed_pitch: 1,
+            ..Default::default()
+        };
+
+        FontBuilder::new()
+            .add_table(&head)
+            .and_then(|builder| builder.add_table(&hhea))
+            .and_then(|builder| builder.add_table(&maxp))
+            .and_then(|builder| builder.add_table(&os2))
+            .and_then(|builder| builder.add_table(&hmtx))
+            .and_then(|builder| builder.add_table(&cmap))
+            .and_then(|builder| builder.add_table(&glyf))
+            .and_then(|builder| builder.add_table(&loca))
+            .and_then(|builder| builder.add_table(&name))
+            .and_then(|builder| builder.add_table(&post))
+            .expect("invalid table of the icon font")
+            .build()
+    }
+
+    /// Get the font in WOFF2 format
+    pub fn to_woff2(&self) -> Vec<u8> {
+        ttf_to_woff2(&self.to_ttf())
+    }
+
+    /// Get the css with the font face loaded from `font_url` and a class
+    /// like `.feather-shield-off` for every icon
+    pub fn to_css(&self, font_url: &str) -> String {
+        let mut css = format!(
+            "@font-face {{
+  font-family: \"{family}\";
+  src: url(\"{url}\") format(\"woff2\");
+  font-weight: normal;
+  font-style: normal;
+  font-display: block;
+}}
+
+[class^=\"{prefix}-\"], [class*=\" {prefix}-\"] {{
+  font-family: \"{family}\" !important;
+  font-style: normal;
+  font-weight: normal;
+  font-variant: normal;
+  text-transform: none;
+  line-height: 1;
+  speak: never;
+  -webkit-font-smoothing: antialiased;
+  -moz-osx-font-smoothing: grayscale;
+}}
+",
+            family = self.family,
+            url = font_url,
+            prefix = self.prefix,
+        );
+
+        for (icon, codepoint) in self.icons.iter().zip(self.codepoints()) {
+            css.push_str(&format!(
+                "\n.{}-{}::before {{\n  content: \"\\{:x}\";\n}}\n",
+                self.prefix,
+                icon.name(),
+                codepoint as u32
+            ));
+        }
+
+        css
+    }
+}
+
+fn get_glyph(icon: &Icon) -> Glyph {
+    let tree =
+        Tree::from_str(&icon.to_svg("24", "#000"), &Options::default()).expect("invalid icon svg");
+    let mut outline = BezPath::new();
+
+    add_outlines(tree.root(), &mut outline);
+
+    if outline.elements().is_empty() {
+        return Glyph::Empty;
+    }
+
+    Glyph::Simple(SimpleGlyph::from_bezpath(&outline).expect("invalid outline of the icon"))
+}
+
+/// Add the outline of the strokes and fills of every path in the group,
+/// flipping the y axis and scaling the view box to the units of the font
+fn add_outlines(group: &Group, outline: &mut BezPath) {
+    let to_font = Transform::from_row(
+        SCALE as f32,
+        0.0,
+        0.0,
+        -SCALE as f32,
+        0.0,
+        (UNITS_PER_EM as i16 + DESCENDER) as f32,
+    );
+
+    for node in group.children() {
+        match node {
+            Node::Group(group) => add_outlines(group, outline),
+            Node::Path(path) => {
+                let transform = path.abs_transform().post_concat(to_font);
+
+                if path.fill().is_some() {
+                    if let Some(fill) = path.data().clone().transform(transform) {
+                        add_path(&fill, outline);
+                    }
+                }
+
+                if let Some(stroke) = path.stroke() {
+                    if let Some(stroke) = path
+                        .data()
+                        .stroke(&stroke.to_tiny_skia(), SCALE as f32)
+                        .and_then(|stroke| stroke.transform(transform))
+                    {
+                        add_path(&stroke, outline);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn add_path(path: &Path, outline: &mut BezPath) {
+    let mut last = Point::ZERO;
+    let mut start = Point::ZERO;
+    let mut open = false;
+
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => {
+                if open {
+                    outline.close_path();
+                }
+                last = get_point(p);
+                start = last;
+                open = true;
+                outline.move_to(last);
+            }
+            PathSegment::LineTo(p) => {
+                last = get_point(p);
+                outline.line_to(last);
+            }
+            PathSegment::QuadTo(p1, p) => {
+                last = get_point(p);
+                outline.quad_to(get_point(p1), last);
+            }
+            PathSegment::CubicTo(p1, p2, p) => {
+                let cubic = CubicBez::new(last, get_point(p1), get_point(p2), get_point(p));
+                for (_, _, quad) in cubic.to_quads(CURVE_ACCURACY) {
+                    outline.quad_to(quad.p1, quad.p2);
+                }
+                last = get_point(p);
+            }
+            PathSegment::Close => {
+                outline.close_path();
+                last = start;
+                open = false;
+            }
+        }
+    }
+
+    if open {
+        outline.close_path();
+    }
+}
+
+fn get_point(point: resvg::tiny_skia::Point) -> Point {
+    Point::new(point.x.round() as f64, point.y.round() as f64)
+}
+
+fn get_name(family: &str) -> Name {
+    let names = [
+        (NameId::FAMILY_NAME, family.to_string()),
+        (NameId::SUBFAMILY_NAME, "Regular".to_string()),
+        (NameId::UNIQUE_ID, format!("{} Regular", family)),
+        (NameId::FULL_NAME, family.to_string()),
+        (NameId::VERSION_STRING, "Version 1.0".to_string()),
+        (NameId::POSTSCRIPT_NAME, family.replace(' ', "")),
+    ];
+
+    Name::new(
+        names
+            .iter()
+            .map(|(id, value)| NameRecord::new(3, 1, 0x409, *id, OffsetMarker::new(value.clone())))
+            .collect(),
+    )
+}
+
+/// Wrap the tables of a TrueType font in a woff2 file
+///
+/// The tables are stored without the woff2 transforms (the null transform
+/// of glyf and loca), which is still valid and only depends on brotli
+fn ttf_to_woff2(ttf: &[u8]) -> Vec<u8> {
+    let num_tables = read_u16(ttf, 4) as usize;
+    let mut tables: Vec<(&[u8], &[u8])> = (0..num_tables)
+        .map(|i| {
+            let record = 12 + i * 16;
+            let offset = read_u32(ttf, record + 8) as usize;
+            let length = read_u32(ttf, record + 12) as usize;
+            (&ttf[record..record + 4], &ttf[offset..offset + length])
+        })
+        .collect();
+
+    // loca must follow glyf in the table directory
+    tables.sort_by_key(|(tag, _)| match *tag {
+        b"loca" => *b"glyg",
+        tag => [tag[0], tag[1], tag[2], tag[3]],
+    });
+
+    let mut directory: Vec<u8> = vec![];
+    let mut data: Vec<u8> = vec![];
+    let mut sfnt_size = 12 + 16 * num_tables as u32;
+
+    for (tag, table) in tables.iter() {
+        let tag_index = WOFF2_KNOWN_TAGS.iter().position(|known| &known[..] == *tag);
+        // transform version 3 is the null transform for glyf and loca, 0 for the rest
+        let transform = if *tag == b"glyf" || *tag == b"loca" {
+            3 << 6
+        } else {
+            0
+        };
+
+        match tag_index {
+            Some(index) => directory.push(transform | index as u8),
+            None => {
+                directory.push(transform | 63);
+                directory.extend(*tag);
+            }
+        }
+
+        write_base_128(&mut directory, table.len() as u32);
+        data.extend(*table);
+        sfnt_size += (table.len() as u32 + 3) & !3;
+    }
+
+    let mut compressed: Vec<u8> = vec![];
+    {
+        let mut writer = CompressorWriter::new(&mut compressed, 4096, 11, 22);
+        writer
+            .write_all(&data)
+            .expect("failed to compress the icon font");
+    }
+
+    let length = 48 + directory.len() + compressed.len();
+    let mut woff2: Vec<u8> = Vec::with_capacity(length);
+
+    woff2.extend(b"wOF2");
+    woff2.extend(&ttf[0..4]);
+    woff2.extend(&(length as u32).to_be_bytes());
+    woff2.extend(&(num_tables as u16).to_be_bytes());
+    woff2.extend(&0u16.to_be_bytes());
+    woff2.extend(&sfnt_size.to_be_bytes());
+    woff2.extend(&(compressed.len() as u32).to_be_bytes());
+    // version of the font
+    woff2.extend(&1u16.to_be_bytes());
+    woff2.extend(&0u16.to_be_bytes());
+    // offset and length of the metadata and private data
+    woff2.extend(&[0; 20]);
+    woff2.extend(directory);
+    woff2.extend(compressed);
+
+    woff2
+}
+
+fn write_base_128(bytes: &mut Vec<u8>, value: u32) {
+    let mut size = 1;
+    while size < 5 && value >> (7 * size) != 0 {
+        size += 1;
+    }
+
+    for i in (0..size).rev() {
+        let byte = ((value >> (7 * i)) & 0x7f) as u8;
+        bytes.push(if i == 0 { byte } else { byte | 0x80 });
+    }
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
@@ -0,0 +1,544 @@
+use crate::svg::to_svg;
+use crate::IconCategory;
+use yew::prelude::*;
+
+#[cfg(feature = "browser_assets")]
+use crate::browser_assets::{self, BrowserIcon};
+#[cfg(feature = "business_assets")]
+use crate::business_assets::{self, BusinessIcon};
+#[cfg(feature = "communication_assets")]
+use crate::communication_assets::{self, CommunicationIcon};
+#[cfg(feature = "controller_assets")]
+use crate::controller_assets::{self, ControllerIcon};
+#[cfg(feature = "dev_assets")]
+use crate::dev_assets::{self, DevIcon};
+#[cfg(feature = "device_assets")]
+use crate::device_assets::{self, DeviceIcon};
+#[cfg(feature = "editing_assets")]
+use crate::editing_assets::{self, EditingIcon};
+#[cfg(feature = "env_assets")]
+use crate::env_assets::{self, EnvIcon};
+#[cfg(feature = "file_assets")]
+use crate::file_assets::{self, FileIcon};
+#[cfg(feature = "info_assets")]
+use crate::info_assets::{self, InfoIcon};
+#[cfg(feature = "multimedia_assets")]
+use crate::multimedia_assets::{self, MultimediaIcon};
+#[cfg(feature = "nav_assets")]
+use crate::nav_assets::{self, NavIcon};
+#[cfg(feature = "object_assets")]
+use crate::object_assets::{self, ObjectIcon};
+#[cfg(feature = "social_assets")]
+use crate::social_assets::{self, SocialIcon};
+#[cfg(feature = "ux_assets")]
+use crate::ux_assets::{self, UxIcon};
+
+/// # Icon
+///
+/// Any icon of the enabled features. It allows to use the same icons
+/// rendered by the `*Assets` components outside of yew, like exporting them as svg files
+///
+/// ## Example
+/// ```rust
+/// use yew_assets::Icon;
+/// use yew_assets::ux_assets::UxIcon;
+///
+/// let svg = Icon::from(UxIcon::ShieldOff).to_svg("48", "#333");
+/// ```
+#[derive(Clone)]
+pub enum Icon {
+    #[cfg(feature = "browser_assets")]
+    Browser(BrowserIcon),
+    #[cfg(feature = "business_assets")]
+    Business(BusinessIcon),
+    #[cfg(feature = "communication_assets")]
+    Communication(CommunicationIcon),
+    #[cfg(feature = "controller_assets")]
+    Controller(ControllerIcon),
+    #[cfg(feature = "dev_assets")]
+    Dev(DevIcon),
+    #[cfg(feature = "device_assets")]
+    Device(DeviceIcon),
+    #[cfg(feature = "editing_assets")]
+    Editing(EditingIcon),
+    #[cfg(feature = "env_assets")]
+    Env(EnvIcon),
+    #[cfg(feature = "file_assets")]
+    File(FileIcon),
+    #[cfg(feature = "info_assets")]
+    Info(InfoIcon),
+    #[cfg(feature = "multimedia_assets")]
+    Multimedia(MultimediaIcon),
+    #[cfg(feature = "nav_assets")]
+    Nav(NavIcon),
+    #[cfg(feature = "object_assets")]
+    Object(ObjectIcon),
+    #[cfg(feature = "social_assets")]
+    Social(SocialIcon),
+    #[cfg(feature = "ux_assets")]
+    Ux(UxIcon),
+}
+
+impl Icon {
+    /// List of all the icons of the enabled features
+    pub fn all() -> Vec<Icon> {
+        IconCategory::ALL
+            .iter()
+            .flat_map(|category| category.icons())
+            .collect()
+    }
+
+    /// Find an icon by its name in the feather collection
+    pub fn from_name(name: &str) -> Option<Icon> {
+        Icon::all().into_iter().find(|icon| icon.name() == name)
+    }
+
+    /// Name of the icon in the feather collection
+    pub fn name(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "browser_assets")]
+            Icon::Browser(ref icon) => icon.name(),
+            #[cfg(feature = "business_assets")]
+            Icon::Business(ref icon) => icon.name(),
+            #[cfg(feature = "communication_assets")]
+            Icon::Communication(ref icon) => icon.name(),
+            #[cfg(feature = "controller_assets")]
+            Icon::Controller(ref icon) => icon.name(),
+            #[cfg(feature = "dev_assets")]
+            Icon::Dev(ref icon) => icon.name(),
+            #[cfg(feature = "device_assets")]
+            Icon::Device(ref icon) => icon.name(),
+            #[cfg(feature = "editing_assets")]
+            Icon::Editing(ref icon) => icon.name(),
+            #[cfg(feature = "env_assets")]
+            Icon::Env(ref icon) => icon.name(),
+            #[cfg(feature = "file_assets")]
+            Icon::File(ref icon) => icon.name(),
+            #[cfg(feature = "info_assets")]
+            Icon::Info(ref icon) => icon.name(),
+            #[cfg(feature = "multimedia_assets")]
+            Icon::Multimedia(ref icon) => icon.name(),
+            #[cfg(feature = "nav_assets")]
+            Icon::Nav(ref icon) => icon.name(),
+            #[cfg(feature = "object_assets")]
+            Icon::Object(ref icon) => icon.name(),
+            #[cfg(feature = "social_assets")]
+            Icon::Social(ref icon) => icon.name(),
+            #[cfg(feature = "ux_assets")]
+            Icon::Ux(ref icon) => icon.name(),
+        }
+    }
+
+    /// Whether the icon points to a direction and has to be mirrored in right-to-left layouts
+    pub fn is_directional(&self) -> bool {
+        match *self {
+            #[cfg(feature = "browser_assets")]
+            Icon::Browser(ref icon) => icon.is_directional(),
+            #[cfg(feature = "business_assets")]
+            Icon::Business(ref icon) => icon.is_directional(),
+            #[cfg(feature = "communication_assets")]
+            Icon::Communication(ref icon) => icon.is_directional(),
+            #[cfg(feature = "controller_assets")]
+            Icon::Controller(ref icon) => icon.is_directional(),
+            #[cfg(feature = "dev_assets")]
+            Icon::Dev(ref icon) => icon.is_directional(),
+            #[cfg(feature = "device_assets")]
+            Icon::Device(ref icon) => icon.is_directional(),
+            #[cfg(feature = "editing_assets")]
+            Icon::Editing(ref icon) => icon.is_directional(),
+            #[cfg(feature = "env_assets")]
+            Icon::Env(ref icon) => icon.is_directional(),
+            #[cfg(feature = "file_assets")]
+            Icon::File(ref icon) => icon.is_directional(),
+            #[cfg(feature = "info_assets")]
+            Icon::Info(ref icon) => icon.is_directional(),
+            #[cfg(feature = "multimedia_assets")]
+            Icon::Multimedia(ref icon) => icon.is_directional(),
+            #[cfg(feature = "nav_assets")]
+            Icon::Nav(ref icon) => icon.is_directional(),
+            #[cfg(feature = "object_assets")]
+            Icon::Object(ref icon) => icon.is_directional(),
+            #[cfg(feature = "social_assets")]
+            Icon::Social(ref icon) => icon.is_directional(),
+            #[cfg(feature = "ux_assets")]
+            Icon::Ux(ref icon) => icon.is_directional(),
+        }
+    }
+
+    /// Index of the elements of the icon which are closed shapes, filled by the filled and duotone variants
+    pub fn fillable(&self) -> &'static [usize] {
+        match *self {
+            #[cfg(feature = "browser_assets")]
+            Icon::Browser(ref icon) => icon.fillable(),
+            #[cfg(feature = "business_assets")]
+            Icon::Business(ref icon) => icon.fillable(),
+            #[cfg(feature = "communication_assets")]
+            Icon::Communication(ref icon) => icon.fillable(),
+            #[cfg(feature = "controller_assets")]
+            Icon::Controller(ref icon) => icon.fillable(),
+            #[cfg(feature = "dev_assets")]
+            Icon::Dev(ref icon) => icon.fillable(),
+            #[cfg(feature = "device_assets")]
+            Icon::Device(ref icon) => icon.fillable(),
+            #[cfg(feature = "editing_assets")]
+            Icon::Editing(ref icon) => icon.fillable(),
+            #[cfg(feature = "env_assets")]
+            Icon::Env(ref icon) => icon.fillable(),
+            #[cfg(feature = "file_assets")]
+            Icon::File(ref icon) => icon.fillable(),
+            #[cfg(feature = "info_assets")]
+            Icon::Info(ref icon) => icon.fillable(),
+            #[cfg(feature = "multimedia_assets")]
+            Icon::Multimedia(ref icon) => icon.fillable(),
+            #[cfg(feature = "nav_assets")]
+            Icon::Nav(ref icon) => icon.fillable(),
+            #[cfg(feature = "object_assets")]
+            Icon::Object(ref icon) => icon.fillable(),
+            #[cfg(feature = "social_assets")]
+            Icon::Social(ref icon) => icon.fillable(),
+            #[cfg(feature = "ux_assets")]
+            Icon::Ux(ref icon) => icon.fillable(),
+        }
+    }
+
+    /// Category of the icon
+    pub fn category(&self) -> IconCategory {
+        match *self {
+            #[cfg(feature = "browser_assets")]
+            Icon::Browser(_) => IconCategory::Browser,
+            #[cfg(feature = "business_assets")]
+            Icon::Business(_) => IconCategory::Business,
+            #[cfg(feature = "communication_assets")]
+            Icon::Communication(_) => IconCategory::Communication,
+            #[cfg(feature = "controller_assets")]
+            Icon::Controller(_) => IconCategory::Controller,
+            #[cfg(feature = "dev_assets")]
+            Icon::Dev(_) => IconCategory::Dev,
+            #[cfg(feature = "device_assets")]
+            Icon::Device(_) => IconCategory::Device,
+            #[cfg(feature = "editing_assets")]
+            Icon::Editing(_) => IconCategory::Editing,
+            #[cfg(feature = "env_assets")]
+            Icon::Env(_) => IconCategory::Env,
+            #[cfg(feature = "file_assets")]
+            Icon::File(_) => IconCategory::File,
+            #[cfg(feature = "info_assets")]
+            Icon::Info(_) => IconCategory::Info,
+            #[cfg(feature = "multimedia_assets")]
+            Icon::Multimedia(_) => IconCategory::Multimedia,
+            #[cfg(feature = "nav_assets")]
+            Icon::Nav(_) => IconCategory::Nav,
+            #[cfg(feature = "object_assets")]
+            Icon::Object(_) => IconCategory::Object,
+            #[cfg(feature = "social_assets")]
+            Icon::Social(_) => IconCategory::Social,
+            #[cfg(feature = "ux_assets")]
+            Icon::Ux(_) => IconCategory::Ux,
+        }
+    }
+
+    /// Get the same svg markup rendered by the `*Assets` component of the icon
+    #[allow(unused_variables)]
+    pub fn html(
+        &self,
+        size: (String, String),
+        view_box: (String, String, String, String),
+        fill: String,
+        class_name: String,
+        id: String,
+    ) -> Html {
+        match *self {
+            #[cfg(feature = "browser_assets")]
+            Icon::Browser(ref icon) => {
+                browser_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "business_assets")]
+            Icon::Business(ref icon) => {
+                business_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "communication_assets")]
+            Icon::Communication(ref icon) => {
+                communication_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "controller_assets")]
+            Icon::Controller(ref icon) => {
+                controller_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "dev_assets")]
+            Icon::Dev(ref icon) => {
+                dev_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "device_assets")]
+            Icon::Device(ref icon) => {
+                device_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "editing_assets")]
+            Icon::Editing(ref icon) => {
+                editing_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "env_assets")]
+            Icon::Env(ref icon) => {
+                env_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "file_assets")]
+            Icon::File(ref icon) => {
+                file_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "info_assets")]
+            Icon::Info(ref icon) => {
+                info_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "multimedia_assets")]
+            Icon::Multimedia(ref icon) => {
+                multimedia_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "nav_assets")]
+            Icon::Nav(ref icon) => {
+                nav_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "object_assets")]
+            Icon::Object(ref icon) => {
+                object_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "social_assets")]
+            Icon::Social(ref icon) => {
+                social_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+            #[cfg(feature = "ux_assets")]
+            Icon::Ux(ref icon) => {
+                ux_assets::get_icon(icon.clone(), size, view_box, fill, class_name, id)
+            }
+        }
+    }
+
+    /// Get the icon as a standalone svg document with the size and the stroke color
+    pub fn to_svg(&self, size: &str, stroke: &str) -> String {
+        let html = self.html(
+            (size.to_string(), size.to_string()),
+            (
+                "0".to_string(),
+                "0".to_string(),
+                "24".to_string(),
+                "24".to_string(),
+            ),
+            "none".to_string(),
+            String::new(),
+            String::new(),
+        );
+
+        to_svg(
+            &html,
+            &[("width", size), ("height", size), ("stroke", stroke)],
+        )
+    }
+}
+
+#[cfg(feature = "browser_assets")]
+impl From<BrowserIcon> for Icon {
+    fn from(icon: BrowserIcon) -> Self {
+        Icon::Browser(icon)
+    }
+}
+
+#[cfg(feature = "browser_assets")]
+impl yew::html::IntoPropValue<Icon> for BrowserIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Browser(self)
+    }
+}
+
+#[cfg(feature = "business_assets")]
+impl From<BusinessIcon> for Icon {
+    fn from(icon: BusinessIcon) -> Self {
+        Icon::Business(icon)
+    }
+}
+
+#[cfg(feature = "business_assets")]
+impl yew::html::IntoPropValue<Icon> for BusinessIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Business(self)
+    }
+}
+
+#[cfg(feature = "communication_assets")]
+impl From<CommunicationIcon> for Icon {
+    fn from(icon: CommunicationIcon) -> Self {
+        Icon::Communication(icon)
+    }
+}
+
+#[cfg(feature = "communication_assets")]
+impl yew::html::IntoPropValue<Icon> for CommunicationIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Communication(self)
+    }
+}
+
+#[cfg(feature = "controller_assets")]
+impl From<ControllerIcon> for Icon {
+    fn from(icon: ControllerIcon) -> Self {
+        Icon::Controller(icon)
+    }
+}
+
+#[cfg(feature = "controller_assets")]
+impl yew::html::IntoPropValue<Icon> for ControllerIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Controller(self)
+    }
+}
+
+#[cfg(feature = "dev_assets")]
+impl From<DevIcon> for Icon {
+    fn from(icon: DevIcon) -> Self {
+        Icon::Dev(icon)
+    }
+}
+
+#[cfg(feature = "dev_assets")]
+impl yew::html::IntoPropValue<Icon> for DevIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Dev(self)
+    }
+}
+
+#[cfg(feature = "device_assets")]
+impl From<DeviceIcon> for Icon {
+    fn from(icon: DeviceIcon) -> Self {
+        Icon::Device(icon)
+    }
+}
+
+#[cfg(feature = "device_assets")]
+impl yew::html::IntoPropValue<Icon> for DeviceIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Device(self)
+    }
+}
+
+#[cfg(feature = "editing_assets")]
+impl From<EditingIcon> for Icon {
+    fn from(icon: EditingIcon) -> Self {
+        Icon::Editing(icon)
+    }
+}
+
+#[cfg(feature = "editing_assets")]
+impl yew::html::IntoPropValue<Icon> for EditingIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Editing(self)
+    }
+}
+
+#[cfg(feature = "env_assets")]
+impl From<EnvIcon> for Icon {
+    fn from(icon: EnvIcon) -> Self {
+        Icon::Env(icon)
+    }
+}
+
+#[cfg(feature = "env_assets")]
+impl yew::html::IntoPropValue<Icon> for EnvIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Env(self)
+    }
+}
+
+#[cfg(feature = "file_assets")]
+impl From<FileIcon> for Icon {
+    fn from(icon: FileIcon) -> Self {
+        Icon::File(icon)
+    }
+}
+
+#[cfg(feature = "file_assets")]
+impl yew::html::IntoPropValue<Icon> for FileIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::File(self)
+    }
+}
+
+#[cfg(feature = "info_assets")]
+impl From<InfoIcon> for Icon {
+    fn from(icon: InfoIcon) -> Self {
+        Icon::Info(icon)
+    }
+}
+
+#[cfg(feature = "info_assets")]
+impl yew::html::IntoPropValue<Icon> for InfoIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Info(self)
+    }
+}
+
+#[cfg(feature = "multimedia_assets")]
+impl From<MultimediaIcon> for Icon {
+    fn from(icon: MultimediaIcon) -> Self {
+        Icon::Multimedia(icon)
+    }
+}
+
+#[cfg(feature = "multimedia_assets")]
+impl yew::html::IntoPropValue<Icon> for MultimediaIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Multimedia(self)
+    }
+}
+
+#[cfg(feature = "nav_assets")]
+impl From<NavIcon> for Icon {
+    fn from(icon: NavIcon) -> Self {
+        Icon::Nav(icon)
+    }
+}
+
+#[cfg(feature = "nav_assets")]
+impl yew::html::IntoPropValue<Icon> for NavIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Nav(self)
+    }
+}
+
+#[cfg(feature = "object_assets")]
+impl From<ObjectIcon> for Icon {
+    fn from(icon: ObjectIcon) -> Self {
+        Icon::Object(icon)
+    }
+}
+
+#[cfg(feature = "object_assets")]
+impl yew::html::IntoPropValue<Icon> for ObjectIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Object(self)
+    }
+}
+
+#[cfg(feature = "social_assets")]
+impl From<SocialIcon> for Icon {
+    fn from(icon: SocialIcon) -> Self {
+        Icon::Social(icon)
+    }
+}
+
+#[cfg(feature = "social_assets")]
+impl yew::html::IntoPropValue<Icon> for SocialIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Social(self)
+    }
+}
+
+#[cfg(feature = "ux_assets")]
+impl From<UxIcon> for Icon {
+    fn from(icon: UxIcon) -> Self {
+        Icon::Ux(icon)
+    }
+}
+
+#[cfg(feature = "ux_assets")]
+impl yew::html::IntoPropValue<Icon> for UxIcon {
+    fn into_prop_value(self) -> Icon {
+        Icon::Ux(self)
+    }
+}
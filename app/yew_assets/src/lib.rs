@@ -44,6 +44,283 @@
 //! }
 //! ```
 //!
+//! ## Variants
+//!
+//! The icons are rendered as outline by default. With the `variant` property the closed shapes of the icon, like the heart or the star, are filled while the lines keep being strokes:
+//! - `IconVariant::Outline`: only the strokes, the `fill` property is applied to the whole svg.
+//! - `IconVariant::Filled`: the closed shapes are filled with the `fill` property, or with `currentColor` when it is `"none"`.
+//! - `IconVariant::Duotone`: the closed shapes are filled with `secondary_color` at `secondary_opacity`, 0.4 by default.
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_assets::communication_assets::{CommunicationAssets, CommunicationIcon};
+//! use yew_assets::IconVariant;
+//!
+//! fn view() -> Html {
+//!     html! {
+//!         <CommunicationAssets
+//!             icon = CommunicationIcon::Heart
+//!             variant = IconVariant::Duotone
+//!             secondary_color = "#e0245e"
+//!             secondary_opacity = 0.3
+//!         />
+//!     }
+//! }
+//! ```
+//!
+//! The fillable elements of every icon are available with `fillable()`.
+//!
+//! ## Transitions
+//!
+//! `IconTransition` animates the change between two icons, like play and pause or menu and close, when `active` changes:
+//! ```rust
+//! use yew_assets::icon_transition::{IconTransition, Transition};
+//!
+//! html! {
+//!     <IconTransition
+//!         from = ControllerIcon::Play
+//!         to = ControllerIcon::Pause
+//!         active = self.playing
+//!         transition = Transition::Rotate
+//!         duration = 300
+//!         easing = "cubic-bezier(0.4, 0, 0.2, 1)"
+//!     />
+//! }
+//! ```
+//!
+//! The transitions are `CrossFade` (default), `Rotate` and `Morph`. With the `morph` feature `Morph` converts both icons into the same number of curves and interpolates the strokes, like the triangle of `ControllerIcon::Play` turning into the bars of `ControllerIcon::Pause` or the lines of `UxIcon::Menu` into `EditingIcon::X`. The strokes without a pair shrink until they hide. Browsers without the `d` css property change the icons without animation, and without the feature it's a `CrossFade`.
+//!
+//! ## Toggles
+//!
+//! `ToggleIcon` is a button which shows one icon of an on/off pair with `aria-pressed` set to its state.
+//! `IconPair` knows the pairs of the crate, like `Visibility`, `Notifications`, `Lock`, `Switch`, `Wifi`, `Video` or `Microphone`, and accepts custom pairs:
+//! ```rust
+//! use yew_assets::toggle_icon::{IconPair, ToggleIcon};
+//!
+//! html! {
+//!     <>
+//!         <ToggleIcon
+//!             pair = IconPair::Visibility
+//!             on = self.visible
+//!             onchange = self.link.callback(Msg::SetVisible)
+//!         />
+//!         <ToggleIcon
+//!             pair = IconPair::custom(CommunicationIcon::Star, ObjectIcon::Square, "Favorite")
+//!             on = self.favorite
+//!             onchange = self.link.callback(Msg::SetFavorite)
+//!         />
+//!     </>
+//! }
+//! ```
+//!
+//! ## Rating
+//!
+//! With the `communication_assets` feature `IconRating` shows a row of stars, or any other icon, to show or select a rating.
+//! Fractional values fill part of the icon, it previews the rating on hover and it works with the arrow keys as an ARIA slider:
+//! ```rust
+//! use yew_assets::icon_rating::IconRating;
+//!
+//! html! {
+//!     <IconRating
+//!         value = self.rating
+//!         max = 5
+//!         step = 0.5
+//!         onchange = self.link.callback(Msg::Rate)
+//!     />
+//! }
+//! ```
+//!
+//! Set `readonly = true` to only show the rating, and `icon` to use another icon, like `icon = CommunicationIcon::Heart`.
+//!
+//! ## Battery level
+//!
+//! With the `device_assets` feature `BatteryLevel` draws `DeviceIcon::Battery` with a bar filled proportionally to the level.
+//! The bar changes its color at the levels of `colors`, red up to 20, orange up to 50 and green by default, and the screen readers read the level like "42 percent":
+//! ```rust
+//! use yew_assets::battery_level::BatteryLevel;
+//!
+//! html! {
+//!     <BatteryLevel
+//!         percent = 42
+//!         charging = false
+//!         colors = vec![(10, "red".to_string()), (100, "currentColor".to_string())]
+//!     />
+//! }
+//! ```
+//!
+//! ## File types
+//!
+//! With the `file_assets` feature `file_icon_for` gets the icon which matches best a MIME type or a file path, using the icons of the enabled features for images, audio, video, code or archives.
+//! `FileTypeIcon` renders it, and `FileIconTable` changes the icon of any type, for one component or for all of them with `update_file_icon_table`:
+//! ```rust
+//! use std::rc::Rc;
+//! use yew::prelude::*;
+//! use yew_assets::file_assets::FileIcon;
+//! use yew_assets::file_type_icon::FileTypeIcon;
+//! use yew_assets::{file_icon_for, update_file_icon_table, FileIconTable};
+//!
+//! assert_eq!(file_icon_for("report.pdf").name(), "file-text");
+//! assert_eq!(file_icon_for("image/png").name(), "image");
+//!
+//! fn view() -> Html {
+//!     let mut table = FileIconTable::default();
+//!     table.insert_extension("log", FileIcon::FileMinus);
+//!
+//!     html! {
+//!         <FileTypeIcon name = "server.log" table = Rc::new(table) />
+//!     }
+//! }
+//!
+//! update_file_icon_table(|table| table.insert_extension("log", FileIcon::FileMinus));
+//! assert_eq!(file_icon_for("server.log").name(), "file-minus");
+//! ```
+//!
+//! ## Weather
+//!
+//! With the `env_assets` feature `WeatherCondition` maps the WMO weather codes, like the ones of Open-Meteo, and the OpenWeather condition ids to the `EnvIcon` of the condition.
+//! The clear sky gets the sun or the moon by the time of the day, and the sunrise or the sunset half an hour around them:
+//! ```rust
+//! use yew_assets::weather_icon::WeatherIcon;
+//! use yew_assets::WeatherCondition;
+//!
+//! let condition = WeatherCondition::from_openweather_id(800).unwrap();
+//! let icon = condition.icon_at(weather.dt, weather.sunrise, weather.sunset);
+//!
+//! html! {
+//!     <WeatherIcon
+//!         condition = WeatherCondition::from_wmo_code(95).unwrap()
+//!         daytime = false
+//!     />
+//! }
+//! ```
+//!
+//! ## Brands
+//!
+//! With the `social_assets` or `dev_assets` features `Brand` gives the official color, the homepage and the share url of the brands of the social and dev icons, like Facebook, Twitter, LinkedIn, GitHub or Figma.
+//! `ShareLink` builds the share url of a page with its text encoded, and shows the icon with the color of the brand with `brand_color`. It renders nothing for the brands without a share url, like Instagram:
+//! ```rust
+//! use yew_assets::share_link::ShareLink;
+//! use yew_assets::Brand;
+//!
+//! let color = Brand::Github.color();
+//! let url = Brand::Facebook.share_url("https://yew.rs", "");
+//!
+//! html! {
+//!     <ShareLink
+//!         network = Brand::Twitter
+//!         url = "https://yew.rs"
+//!         text = "Yew assets"
+//!         brand_color = true
+//!     />
+//! }
+//! ```
+//!
+//! ## Categories
+//!
+//! `IconCategory` has the categories of the enabled features with their display names and descriptions, useful to group the icons in a picker:
+//! ```rust
+//! use yew_assets::IconCategory;
+//!
+//! for category in IconCategory::ALL {
+//!     println!("{}: {}", category.display_name(), category.description());
+//!
+//!     for icon in category.icons() {
+//!         println!("    {}", icon.name());
+//!     }
+//! }
+//! ```
+//!
+//! Every icon knows its category with `Icon::category()`.
+//!
+//! ## Command line
+//!
+//! The `cli` feature builds the `yew-assets` binary, which uses the same icons rendered by the components:
+//! ```bash
+//! cargo install yew_assets --features cli
+//! yew-assets list --category ux
+//! yew-assets search arrow
+//! yew-assets show shield-off > shield-off.svg
+//! yew-assets export --out icons --size 48 --stroke "#333"
+//! ```
+//!
+//! ## Png rasterisation
+//!
+//! The `raster` feature provides `render_png` to get a png of the icons without a browser, useful for favicons, push notifications or native app packaging:
+//! ```rust
+//! use yew_assets::{render_png, Icon};
+//! use yew_assets::dev_assets::DevIcon;
+//!
+//! let png: Vec<u8> = render_png(&Icon::from(DevIcon::Terminal), 192, "#333");
+//! ```
+//!
+//! ## Favicon set
+//!
+//! With the `raster` feature `Favicon` generates from any icon the `favicon.ico`, the 16, 32, 180, 192 and 512 pngs, the maskable variants and the icons of the `manifest.json`:
+//! ```rust,no_run
+//! use yew_assets::Favicon;
+//! use yew_assets::dev_assets::DevIcon;
+//!
+//! let mut favicon = Favicon::new(DevIcon::Terminal);
+//! favicon.foreground = "#fff".to_string();
+//! favicon.background = "#1e1e1e".to_string();
+//! favicon.corner_radius = 0.2;
+//!
+//! favicon.write("static/favicon").unwrap();
+//! println!("{}", favicon.manifest_icons("/static/favicon"));
+//! ```
+//!
+//! It is also available from the command line:
+//! ```bash
+//! yew-assets favicon terminal --out static/favicon --stroke "#fff" --background "#1e1e1e" --radius 0.2
+//! ```
+//!
+//! ## Icon font
+//!
+//! The `font` feature compiles the icons into a WOFF2 font with a css file, for plain html pages which can't use the components.
+//! It includes the icons of the enabled categories, and every category gets a stable block of codepoints in the private use area, so the classes don't change between versions:
+//! ```rust,no_run
+//! use yew_assets::{Icon, IconFont};
+//!
+//! let font = IconFont::new(Icon::all());
+//!
+//! std::fs::write("feather.woff2", font.to_woff2()).unwrap();
+//! std::fs::write("feather.css", font.to_css("feather.woff2")).unwrap();
+//! ```
+//!
+//! ```html
+//! <link href="feather.css" rel="stylesheet">
+//! <i class="feather-shield-off"></i>
+//! ```
+//!
+//! It is also available from the command line:
+//! ```bash
+//! yew-assets font --out static/fonts --category ux,dev
+//! ```
+//!
+//! ## Right-to-left layouts
+//!
+//! The direction-sensitive icons, like arrows, chevrons, play or send, are mirrored in right-to-left layouts while the symmetric ones stay untouched.
+//! Set the direction for the whole app through the `LayoutDirection` context, which renders again the mounted components when it changes, or per component with the `rtl` property:
+//! ```rust,no_run
+//! use yew_assets::set_rtl;
+//!
+//! set_rtl(true);
+//! ```
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_assets::controller_assets::{ControllerAssets, ControllerIcon};
+//!
+//! fn view() -> Html {
+//!     html! {
+//!         <ControllerAssets
+//!             icon = ControllerIcon::ArrowRight
+//!             rtl = false
+//!         />
+//!     }
+//! }
+//! ```
+//!
 //! ## How run documentation page
 //!
 //! 1. Clone the repository:
@@ -61,8 +338,84 @@
 //! in this repository then soon the component will be generated.
 //! For fixes please open directly a pull request.
 #![recursion_limit = "512"]
+#[cfg(any(feature = "social_assets", feature = "dev_assets"))]
+mod brand;
+mod category;
 mod components;
+mod direction;
+#[cfg(feature = "raster")]
+mod favicon;
+#[cfg(feature = "file_assets")]
+mod file_type;
+#[cfg(all(
+    feature = "font",
+    any(
+        feature = "browser_assets",
+        feature = "business_assets",
+        feature = "communication_assets",
+        feature = "controller_assets",
+        feature = "dev_assets",
+        feature = "device_assets",
+        feature = "editing_assets",
+        feature = "env_assets",
+        feature = "file_assets",
+        feature = "info_assets",
+        feature = "multimedia_assets",
+        feature = "nav_assets",
+        feature = "object_assets",
+        feature = "social_assets",
+        feature = "ux_assets",
+    )
+))]
+mod font;
+mod icon;
+#[cfg(feature = "morph")]
+mod morph;
+#[cfg(feature = "raster")]
+mod raster;
+mod svg;
+mod variant;
+#[cfg(feature = "env_assets")]
+mod weather;
 
+#[cfg(any(feature = "social_assets", feature = "dev_assets"))]
+pub use brand::Brand;
+pub use category::IconCategory;
+pub use direction::{is_rtl, set_rtl, LayoutDirection};
+#[cfg(feature = "raster")]
+pub use favicon::Favicon;
+#[cfg(feature = "file_assets")]
+pub use file_type::{file_icon_for, set_file_icon_table, update_file_icon_table, FileIconTable};
+#[cfg(all(
+    feature = "font",
+    any(
+        feature = "browser_assets",
+        feature = "business_assets",
+        feature = "communication_assets",
+        feature = "controller_assets",
+        feature = "dev_assets",
+        feature = "device_assets",
+        feature = "editing_assets",
+        feature = "env_assets",
+        feature = "file_assets",
+        feature = "info_assets",
+        feature = "multimedia_assets",
+        feature = "nav_assets",
+        feature = "object_assets",
+        feature = "social_assets",
+        feature = "ux_assets",
+    )
+))]
+pub use font::IconFont;
+pub use icon::Icon;
+#[cfg(feature = "raster")]
+pub use raster::render_png;
+pub use variant::IconVariant;
+#[cfg(feature = "env_assets")]
+pub use weather::{is_daytime, WeatherCondition};
+
+#[cfg(feature = "device_assets")]
+pub use components::battery_level;
 #[cfg(feature = "browser_assets")]
 pub use components::browser_assets;
 #[cfg(feature = "business_assets")]
@@ -81,6 +434,11 @@ pub use components::editing_assets;
 pub use components::env_assets;
 #[cfg(feature = "file_assets")]
 pub use components::file_assets;
+#[cfg(feature = "file_assets")]
+pub use components::file_type_icon;
+#[cfg(feature = "communication_assets")]
+pub use components::icon_rating;
+pub use components::icon_transition;
 #[cfg(feature = "info_assets")]
 pub use components::info_assets;
 #[cfg(feature = "multimedia_assets")]
@@ -89,7 +447,29 @@ pub use components::multimedia_assets;
 pub use components::nav_assets;
 #[cfg(feature = "object_assets")]
 pub use components::object_assets;
+#[cfg(any(feature = "social_assets", feature = "dev_assets"))]
+pub use components::share_link;
 #[cfg(feature = "social_assets")]
 pub use components::social_assets;
+#[cfg(any(
+    feature = "browser_assets",
+    feature = "business_assets",
+    feature = "communication_assets",
+    feature = "controller_assets",
+    feature = "dev_assets",
+    feature = "device_assets",
+    feature = "editing_assets",
+    feature = "env_assets",
+    feature = "file_assets",
+    feature = "info_assets",
+    feature = "multimedia_assets",
+    feature = "nav_assets",
+    feature = "object_assets",
+    feature = "social_assets",
+    feature = "ux_assets",
+))]
+pub use components::toggle_icon;
 #[cfg(feature = "ux_assets")]
 pub use components::ux_assets;
+#[cfg(feature = "env_assets")]
+pub use components::weather_icon;
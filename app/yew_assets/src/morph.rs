@@ -0,0 +1,291 @@
+use kurbo::{BezPath, CubicBez, ParamCurve, ParamCurveArclen, PathEl, Point, QuadBez};
+use yew::virtual_dom::{VNode, VTag};
+use yew::Html;
+
+/// Render the active icon as cubic curves with the same structure of the other icon,
+/// so the browser interpolates the `d` property between them. Returns `None` if the
+/// icons have elements which can't be converted into paths
+pub(crate) fn get_morph(from: &Html, to: &Html, active: bool, timing: &str) -> Option<Html> {
+    let (from, to) = match (from, to) {
+        (VNode::VTag(from), VNode::VTag(to)) => (from, to),
+        _ => return None,
+    };
+    let mut from_subpaths = get_subpaths(&get_paths(from)?)?;
+    let mut to_subpaths = get_subpaths(&get_paths(to)?)?;
+
+    // the extra subpaths of an icon shrink into the middle of their pair, hidden
+    let visible = (from_subpaths.len(), to_subpaths.len());
+    fill_subpaths(&mut from_subpaths, &to_subpaths);
+    fill_subpaths(&mut to_subpaths, &from_subpaths);
+
+    let (mut root, mut subpaths, others, visible) = if active {
+        (to.clone(), to_subpaths, from_subpaths, visible.1)
+    } else {
+        (from.clone(), from_subpaths, to_subpaths, visible.0)
+    };
+
+    root.children.clear();
+    root.children
+        .extend(subpaths.iter_mut().zip(others.iter()).enumerate().map(
+            |(index, (subpath, other))| {
+                split_curves(subpath, other.curves.len());
+                let d = subpath.to_path();
+                let mut path = VTag::new("path");
+                path.add_attribute(
+                    "style",
+                    format!(
+                        "d: path('{}'); opacity: {}; transition: {}",
+                        d,
+                        if index < visible { 1 } else { 0 },
+                        timing
+                    ),
+                );
+                path.add_attribute("d", d);
+                VNode::VTag(Box::new(path))
+            },
+        ));
+
+    Some(VNode::VTag(root))
+}
+
+/// Continuous part of a path as cubic curves, the closed ones end with a curve to the start
+#[derive(Clone)]
+struct Subpath {
+    start: Point,
+    curves: Vec<CubicBez>,
+}
+
+impl Subpath {
+    fn to_path(&self) -> String {
+        let number = |n: f64| ((n * 1000.0).round() / 1000.0).to_string();
+        let point = |p: Point| format!("{} {}", number(p.x), number(p.y));
+        let curves: Vec<String> = self
+            .curves
+            .iter()
+            .map(|curve| {
+                format!(
+                    "C{} {} {}",
+                    point(curve.p1),
+                    point(curve.p2),
+                    point(curve.p3)
+                )
+            })
+            .collect();
+
+        format!("M{}{}", point(self.start), curves.join(""))
+    }
+}
+
+fn get_subpaths(paths: &[String]) -> Option<Vec<Subpath>> {
+    let mut subpaths: Vec<Subpath> = vec![];
+
+    for path in paths {
+        let path = BezPath::from_svg(path).ok()?;
+        let mut current = Point::ZERO;
+
+        for element in path.elements() {
+            let curve = match *element {
+                PathEl::MoveTo(point) => {
+                    subpaths.push(Subpath {
+                        start: point,
+                        curves: vec![],
+                    });
+                    current = point;
+                    continue;
+                }
+                PathEl::LineTo(point) => get_line(current, point),
+                PathEl::QuadTo(p1, p2) => QuadBez::new(current, p1, p2).raise(),
+                PathEl::CurveTo(p1, p2, p3) => CubicBez::new(current, p1, p2, p3),
+                PathEl::ClosePath => {
+                    let start = subpaths.last()?.start;
+                    if current == start {
+                        continue;
+                    }
+                    get_line(current, start)
+                }
+            };
+
+            current = curve.p3;
+            subpaths.last_mut()?.curves.push(curve);
+        }
+    }
+
+    Some(subpaths)
+}
+
+fn get_line(from: Point, to: Point) -> CubicBez {
+    CubicBez::new(from, from.lerp(to, 1.0 / 3.0), from.lerp(to, 2.0 / 3.0), to)
+}
+
+/// Add the subpaths missing to get the same number of the other icon,
+/// as points in the middle of the subpaths they are paired with
+fn fill_subpaths(subpaths: &mut Vec<Subpath>, others: &[Subpath]) {
+    for other in others.iter().skip(subpaths.len()) {
+        let middle = match other.curves.get(other.curves.len() / 2) {
+            Some(curve) => curve.p0,
+            None => other.start,
+        };
+
+        subpaths.push(Subpath {
+            start: middle,
+            curves: vec![CubicBez::new(middle, middle, middle, middle)],
+        });
+    }
+}
+
+/// Split the longest curves in two until the subpath has the number of curves
+fn split_curves(subpath: &mut Subpath, count: usize) {
+    if subpath.curves.is_empty() {
+        subpath.curves.push(CubicBez::new(
+            subpath.start,
+            subpath.start,
+            subpath.start,
+            subpath.start,
+        ));
+    }
+
+    while subpath.curves.len() < count {
+        let (index, _) = subpath
+            .curves
+            .iter()
+            .map(|curve| curve.arclen(0.1))
+            .enumerate()
+            .fold((0, -1.0), |longest, (index, length)| {
+                if length > longest.1 {
+                    (index, length)
+                } else {
+                    longest
+                }
+            });
+        let (first, second) = subpath.curves[index].subdivide();
+        subpath.curves.splice(index..=index, [first, second]);
+    }
+}
+
+/// Get every element of the svg as a path
+fn get_paths(svg: &VTag) -> Option<Vec<String>> {
+    svg.children
+        .iter()
+        .filter_map(|child| match child {
+            VNode::VTag(child) => Some(child),
+            _ => None,
+        })
+        .map(|child| get_path(child))
+        .collect()
+}
+
+fn get_path(tag: &VTag) -> Option<String> {
+    let attribute = |key: &str| {
+        tag.attributes
+            .iter()
+            .find(|(attribute_key, _)| *attribute_key == key)
+            .map(|(_, value)| value.to_string())
+    };
+    let number = |key: &str| {
+        attribute(key)
+            .and_then(|value| value.parse::<f64>().ok())
+            .unwrap_or(0.0)
+    };
+
+    match tag.tag() {
+        "path" => attribute("d"),
+        "line" => Some(format!(
+            "M{} {}L{} {}",
+            number("x1"),
+            number("y1"),
+            number("x2"),
+            number("y2")
+        )),
+        "polyline" | "polygon" => {
+            let points = get_numbers(&attribute("points")?);
+            let mut path: Vec<String> = points
+                .chunks(2)
+                .enumerate()
+                .map(|(index, point)| {
+                    let command = if index == 0 { "M" } else { "L" };
+                    format!("{}{} {}", command, point[0], point.get(1).unwrap_or(&0.0))
+                })
+                .collect();
+
+            if tag.tag() == "polygon" {
+                path.push("Z".to_string());
+            }
+
+            Some(path.join(""))
+        }
+        "circle" | "ellipse" => {
+            let (rx, ry) = if tag.tag() == "circle" {
+                (number("r"), number("r"))
+            } else {
+                (number("rx"), number("ry"))
+            };
+
+            Some(format!(
+                "M{} {}a{rx} {ry} 0 1 0 {} 0a{rx} {ry} 0 1 0 {} 0",
+                number("cx") - rx,
+                number("cy"),
+                rx * 2.0,
+                -rx * 2.0,
+                rx = rx,
+                ry = ry
+            ))
+        }
+        "rect" => {
+            let (x, y, width, height) =
+                (number("x"), number("y"), number("width"), number("height"));
+            let rx = number("rx").min(width / 2.0);
+            let ry = match attribute("ry") {
+                Some(_) => number("ry").min(height / 2.0),
+                None => rx.min(height / 2.0),
+            };
+
+            Some(format!(
+                "M{} {}h{}a{rx} {ry} 0 0 1 {rx} {ry}v{}a{rx} {ry} 0 0 1 {} {ry}h{}a{rx} {ry} 0 0 1 {} {}v{}a{rx} {ry} 0 0 1 {rx} {}Z",
+                x + rx,
+                y,
+                width - rx * 2.0,
+                height - ry * 2.0,
+                -rx,
+                -(width - rx * 2.0),
+                -rx,
+                -ry,
+                -(height - ry * 2.0),
+                -ry,
+                rx = rx,
+                ry = ry
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Parse the numbers of an attribute, like `M.5-1` or `12 2 2 7`
+fn get_numbers(value: &str) -> Vec<f64> {
+    let mut numbers = vec![];
+    let mut number = String::new();
+
+    for c in value.chars() {
+        let is_new_number = match c {
+            '-' | '+' => !number.ends_with('e'),
+            '.' => number.contains('.'),
+            _ => false,
+        };
+
+        if is_new_number || c == ',' || c.is_whitespace() {
+            if let Ok(n) = number.parse::<f64>() {
+                numbers.push(n);
+            }
+            number.clear();
+        }
+
+        if c != ',' && !c.is_whitespace() {
+            number.push(c);
+        }
+    }
+
+    if let Ok(n) = number.parse::<f64>() {
+        numbers.push(n);
+    }
+
+    numbers
+}
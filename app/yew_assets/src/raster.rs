@@ -0,0 +1,43 @@
+use crate::Icon;
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg::{Options, Tree};
+
+/// Rasterise an icon to png without a browser
+///
+/// The icon is rendered from the same svg markup of the `*Assets` components,
+/// scaled to a square of `size` pixels with the stroke painted in `colour`.
+/// The colour accepts any svg color value like `"#333"` or `"purple"`.
+///
+/// ## Feature
+/// raster
+///
+/// ## Example
+/// ```rust
+/// use yew_assets::{render_png, Icon};
+/// use yew_assets::dev_assets::DevIcon;
+///
+/// let favicon = render_png(&Icon::from(DevIcon::Terminal), 32, "#333");
+/// ```
+///
+/// ## Panics
+/// If the size is `0`
+pub fn render_png(icon: &Icon, size: u32, colour: &str) -> Vec<u8> {
+    svg_to_png(&icon.to_svg(&size.to_string(), colour), size)
+}
+
+pub(crate) fn svg_to_png(svg: &str, size: u32) -> Vec<u8> {
+    let tree = Tree::from_str(svg, &Options::default()).expect("invalid icon svg");
+
+    let mut pixmap = Pixmap::new(size, size).expect("the size of the png must be greater than 0");
+    let scale = size as f32 / tree.size().width().max(tree.size().height());
+
+    resvg::render(
+        &tree,
+        Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap
+        .encode_png()
+        .expect("failed to encode the icon as png")
+}
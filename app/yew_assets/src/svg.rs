@@ -0,0 +1,74 @@
+use yew::prelude::*;
+use yew::virtual_dom::VNode;
+
+/// Serialize the markup of an icon into a standalone svg document
+///
+/// The `overrides` replace (or add) attributes of the root `<svg>` element,
+/// so the same definition used by the components can be exported with
+/// another stroke color for example.
+pub(crate) fn to_svg(node: &Html, overrides: &[(&str, &str)]) -> String {
+    let mut svg = String::new();
+    write_node(&mut svg, node, overrides);
+    svg
+}
+
+fn write_node(svg: &mut String, node: &VNode, overrides: &[(&str, &str)]) {
+    match node {
+        VNode::VTag(tag) => {
+            svg.push('<');
+            svg.push_str(tag.tag());
+
+            for (key, value) in tag.attributes.iter() {
+                if value.is_empty() && (key == "id" || key == "class") {
+                    continue;
+                }
+
+                let value = overrides
+                    .iter()
+                    .find(|(override_key, _)| *override_key == key)
+                    .map_or(value, |(_, override_value)| override_value);
+
+                write_attribute(svg, key, value);
+            }
+
+            for (key, value) in overrides {
+                if !tag.attributes.iter().any(|(tag_key, _)| tag_key == *key) {
+                    write_attribute(svg, key, value);
+                }
+            }
+
+            svg.push('>');
+
+            for child in tag.children.iter() {
+                write_node(svg, child, &[]);
+            }
+
+            svg.push_str("</");
+            svg.push_str(tag.tag());
+            svg.push('>');
+        }
+        VNode::VList(list) => {
+            for child in list.iter() {
+                write_node(svg, child, overrides);
+            }
+        }
+        VNode::VText(text) => svg.push_str(&escape(&text.text)),
+        VNode::VComp(_) | VNode::VRef(_) => (),
+    }
+}
+
+fn write_attribute(svg: &mut String, key: &str, value: &str) {
+    svg.push(' ');
+    svg.push_str(key);
+    svg.push_str("=\"");
+    svg.push_str(&escape(value));
+    svg.push('"');
+}
+
+pub(crate) fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
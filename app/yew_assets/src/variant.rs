@@ -0,0 +1,96 @@
+/// # IconVariant
+///
+/// How the `*Assets` components render the icons
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_assets::communication_assets::{CommunicationAssets, CommunicationIcon};
+/// use yew_assets::IconVariant;
+///
+/// fn view() -> Html {
+///     html! {
+///         <CommunicationAssets
+///             icon = CommunicationIcon::Heart
+///             variant = IconVariant::Duotone
+///             secondary_color = "#e0245e"
+///         />
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IconVariant {
+    /// Only the strokes, the `fill` property is applied to the whole svg
+    #[default]
+    Outline,
+    /// The closed shapes of the icon are filled with the `fill` property,
+    /// or with `currentColor` when it is `"none"`
+    Filled,
+    /// The closed shapes of the icon are filled with the `secondary_color`
+    /// property at `secondary_opacity`
+    Duotone,
+}
+
+/// Fill the fillable elements of the icon depending on the variant,
+/// the lines are never filled so they don't get a shape between their ends
+#[cfg(any(
+    feature = "browser_assets",
+    feature = "business_assets",
+    feature = "communication_assets",
+    feature = "controller_assets",
+    feature = "dev_assets",
+    feature = "device_assets",
+    feature = "editing_assets",
+    feature = "env_assets",
+    feature = "file_assets",
+    feature = "info_assets",
+    feature = "multimedia_assets",
+    feature = "nav_assets",
+    feature = "object_assets",
+    feature = "social_assets",
+    feature = "ux_assets",
+))]
+pub(crate) fn get_variant_icon(
+    icon: yew::Html,
+    fillable: &[usize],
+    variant: IconVariant,
+    fill: &str,
+    secondary_color: &str,
+    secondary_opacity: f32,
+) -> yew::Html {
+    use yew::virtual_dom::VNode;
+
+    let (color, opacity) = match variant {
+        IconVariant::Outline => return icon,
+        IconVariant::Filled if fill == "none" => ("currentColor".to_string(), None),
+        IconVariant::Filled => (fill.to_string(), None),
+        IconVariant::Duotone => (secondary_color.to_string(), Some(secondary_opacity)),
+    };
+
+    match icon {
+        VNode::VTag(mut tag) => {
+            tag.add_attribute("fill", "none");
+
+            let children = tag
+                .children
+                .iter_mut()
+                .filter_map(|child| match child {
+                    VNode::VTag(child) => Some(child),
+                    _ => None,
+                })
+                .enumerate()
+                .filter(|(index, _)| fillable.contains(index));
+
+            for (_, child) in children {
+                child.add_attribute("fill", color.clone());
+
+                if let Some(opacity) = opacity {
+                    child.add_attribute("fill-opacity", opacity.to_string());
+                }
+            }
+
+            VNode::VTag(tag)
+        }
+        icon => icon,
+    }
+}
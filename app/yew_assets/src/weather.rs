@@ -0,0 +1,133 @@
+use crate::env_assets::EnvIcon;
+
+/// Seconds around the sunrise and the sunset which show `EnvIcon::Sunrise` and `EnvIcon::Sunset`
+const TWILIGHT: i64 = 30 * 60;
+
+/// # WeatherCondition
+///
+/// Weather conditions of the WMO weather codes, used by services like Open-Meteo,
+/// and of the OpenWeather condition ids, with the `EnvIcon` which represents them
+///
+/// ## Feature
+/// env_assets
+///
+/// ## Example
+/// ```rust
+/// use yew_assets::WeatherCondition;
+///
+/// let condition = WeatherCondition::from_wmo_code(63).unwrap();
+///
+/// assert_eq!(condition, WeatherCondition::Rain);
+/// assert_eq!(condition.icon(true).name(), "cloud-rain");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WeatherCondition {
+    /// Clear or mainly clear sky
+    Clear,
+    /// Partly cloudy sky
+    PartlyCloudy,
+    /// Cloudy or overcast sky
+    Cloudy,
+    /// Fog, mist, haze, smoke or dust
+    Fog,
+    /// Light rain in small drops
+    Drizzle,
+    /// Rain and rain showers
+    Rain,
+    /// Rain or drizzle which freezes on the ground
+    FreezingRain,
+    /// Snow, snow grains and snow showers
+    Snow,
+    /// Rain mixed with snow
+    Sleet,
+    /// Thunderstorm, with or without rain or hail
+    Thunderstorm,
+    /// Squalls and tornadoes
+    Wind,
+}
+
+impl WeatherCondition {
+    /// Condition of a WMO weather code (WMO 4677), like the `weathercode` of Open-Meteo
+    pub fn from_wmo_code(code: u8) -> Option<WeatherCondition> {
+        match code {
+            0 | 1 => Some(WeatherCondition::Clear),
+            2 => Some(WeatherCondition::PartlyCloudy),
+            3 => Some(WeatherCondition::Cloudy),
+            45 | 48 => Some(WeatherCondition::Fog),
+            51 | 53 | 55 => Some(WeatherCondition::Drizzle),
+            56 | 57 | 66 | 67 => Some(WeatherCondition::FreezingRain),
+            61 | 63 | 65 | 80 | 81 | 82 => Some(WeatherCondition::Rain),
+            71 | 73 | 75 | 77 | 85 | 86 => Some(WeatherCondition::Snow),
+            95 | 96 | 99 => Some(WeatherCondition::Thunderstorm),
+            _ => None,
+        }
+    }
+
+    /// Condition of an OpenWeather condition id, like the `weather[].id` of its current weather
+    pub fn from_openweather_id(id: u16) -> Option<WeatherCondition> {
+        match id {
+            200..=232 => Some(WeatherCondition::Thunderstorm),
+            300..=321 => Some(WeatherCondition::Drizzle),
+            511 => Some(WeatherCondition::FreezingRain),
+            500..=531 => Some(WeatherCondition::Rain),
+            611..=616 => Some(WeatherCondition::Sleet),
+            600..=622 => Some(WeatherCondition::Snow),
+            771 | 781 => Some(WeatherCondition::Wind),
+            701..=762 => Some(WeatherCondition::Fog),
+            800 | 801 => Some(WeatherCondition::Clear),
+            802 => Some(WeatherCondition::PartlyCloudy),
+            803 | 804 => Some(WeatherCondition::Cloudy),
+            _ => None,
+        }
+    }
+
+    /// Description of the condition, used as label for the screen readers
+    pub fn description(&self) -> &'static str {
+        match self {
+            WeatherCondition::Clear => "Clear sky",
+            WeatherCondition::PartlyCloudy => "Partly cloudy",
+            WeatherCondition::Cloudy => "Cloudy",
+            WeatherCondition::Fog => "Fog",
+            WeatherCondition::Drizzle => "Drizzle",
+            WeatherCondition::Rain => "Rain",
+            WeatherCondition::FreezingRain => "Freezing rain",
+            WeatherCondition::Snow => "Snow",
+            WeatherCondition::Sleet => "Sleet",
+            WeatherCondition::Thunderstorm => "Thunderstorm",
+            WeatherCondition::Wind => "Wind",
+        }
+    }
+
+    /// Icon of the condition, the clear sky gets `EnvIcon::Sun` by day and `EnvIcon::Moon` by night
+    pub fn icon(&self, daytime: bool) -> EnvIcon {
+        match self {
+            WeatherCondition::Clear if daytime => EnvIcon::Sun,
+            WeatherCondition::Clear => EnvIcon::Moon,
+            WeatherCondition::PartlyCloudy | WeatherCondition::Cloudy | WeatherCondition::Fog => {
+                EnvIcon::Cloud
+            }
+            WeatherCondition::Drizzle => EnvIcon::CloudDrizzle,
+            WeatherCondition::Rain | WeatherCondition::FreezingRain => EnvIcon::CloudRain,
+            WeatherCondition::Snow | WeatherCondition::Sleet => EnvIcon::CloudSnow,
+            WeatherCondition::Thunderstorm => EnvIcon::CloudLightning,
+            WeatherCondition::Wind => EnvIcon::Wind,
+        }
+    }
+
+    /// Icon of the condition at a time, with the unix timestamps in seconds of the time,
+    /// the sunrise and the sunset of the same day like OpenWeather returns them.
+    /// The clear sky gets `EnvIcon::Sunrise` and `EnvIcon::Sunset` half an hour
+    /// around them, and `EnvIcon::Sun` or `EnvIcon::Moon` the rest of the day
+    pub fn icon_at(&self, timestamp: i64, sunrise: i64, sunset: i64) -> EnvIcon {
+        match self {
+            WeatherCondition::Clear if (timestamp - sunrise).abs() <= TWILIGHT => EnvIcon::Sunrise,
+            WeatherCondition::Clear if (timestamp - sunset).abs() <= TWILIGHT => EnvIcon::Sunset,
+            _ => self.icon(is_daytime(timestamp, sunrise, sunset)),
+        }
+    }
+}
+
+/// Whether the timestamp is between the sunrise and the sunset, all of them unix timestamps in seconds
+pub fn is_daytime(timestamp: i64, sunrise: i64, sunset: i64) -> bool {
+    timestamp >= sunrise && timestamp < sunset
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "full")]
+
+use yew_assets::social_assets::SocialIcon;
+use yew_assets::{Brand, Icon};
+
+#[test]
+fn should_encode_the_share_urls() {
+    assert_eq!(
+        Brand::Facebook.share_url("https://yew.rs/?a=1&b=2", "ignored"),
+        Some(
+            "https://www.facebook.com/sharer/sharer.php?u=https%3A%2F%2Fyew.rs%2F%3Fa%3D1%26b%3D2"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        Brand::Twitter.share_url("https://yew.rs", "Olá #yew"),
+        Some(
+            "https://twitter.com/intent/tweet?url=https%3A%2F%2Fyew.rs&text=Ol%C3%A1%20%23yew"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        Brand::Linkedin.share_url("https://yew.rs", ""),
+        Some(
+            "https://www.linkedin.com/sharing/share-offsite/?url=https%3A%2F%2Fyew.rs".to_string()
+        )
+    );
+}
+
+#[test]
+fn should_not_share_without_template() {
+    assert_eq!(Brand::Instagram.share_url("https://yew.rs", ""), None);
+    assert_eq!(Brand::Github.share_url("https://yew.rs", ""), None);
+}
+
+#[test]
+fn should_find_the_brand_of_an_icon() {
+    for brand in Brand::ALL {
+        assert_eq!(Brand::from_icon(&brand.icon()), Some(*brand));
+        assert!(brand.color().starts_with('#') && brand.color().len() == 7);
+        assert!(brand.homepage().starts_with("https://"));
+    }
+    assert_eq!(Brand::from_icon(&Icon::from(SocialIcon::Rss)), None);
+}
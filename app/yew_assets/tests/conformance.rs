@@ -0,0 +1,244 @@
+#![cfg(feature = "full")]
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use yew_assets::Icon;
+
+/// Root attributes shared by every svg of the feather collection
+const FEATHER_ROOT: [(&str, &str); 9] = [
+    ("xmlns", "http://www.w3.org/2000/svg"),
+    ("width", "24"),
+    ("height", "24"),
+    ("viewBox", "0 0 24 24"),
+    ("fill", "none"),
+    ("stroke", "currentColor"),
+    ("stroke-width", "2"),
+    ("stroke-linecap", "round"),
+    ("stroke-linejoin", "round"),
+];
+
+#[derive(Debug, PartialEq)]
+struct Element {
+    name: String,
+    attributes: BTreeMap<String, String>,
+    children: Vec<Element>,
+}
+
+fn render(icon: &Icon) -> String {
+    icon.to_svg("24", "currentColor")
+}
+
+/// Parse the svg in a tree of elements, with the numbers of the attributes normalised
+/// and without the `class` of the root, which feather uses for its own name
+fn parse(svg: &str) -> Result<Element, String> {
+    let document = roxmltree::Document::parse(svg).map_err(|e| e.to_string())?;
+    let mut root = get_element(document.root_element());
+    root.attributes.remove("class");
+
+    Ok(root)
+}
+
+fn get_element(node: roxmltree::Node) -> Element {
+    Element {
+        name: node.tag_name().name().to_string(),
+        attributes: node
+            .attributes()
+            .map(|attribute| (attribute.name().to_string(), normalise(attribute.value())))
+            .chain(
+                node.tag_name()
+                    .namespace()
+                    .filter(|_| node.parent_element().is_none())
+                    .map(|namespace| ("xmlns".to_string(), namespace.to_string())),
+            )
+            .collect(),
+        children: node
+            .children()
+            .filter(|n| n.is_element())
+            .map(get_element)
+            .collect(),
+    }
+}
+
+/// Normalise numbers and separators, so `M.5,1L2 3` and `M 0.5 1 L 2 3` are equal
+fn normalise(value: &str) -> String {
+    if !value.chars().any(|c| c.is_ascii_digit()) || value.contains("://") {
+        return value.trim().to_string();
+    }
+
+    let mut tokens: Vec<String> = vec![];
+    let mut number = String::new();
+
+    let push_number = |number: &mut String, tokens: &mut Vec<String>| {
+        if !number.is_empty() {
+            match number.parse::<f64>() {
+                Ok(n) => tokens.push(n.to_string()),
+                Err(_) => tokens.push(number.clone()),
+            }
+            number.clear();
+        }
+    };
+
+    for c in value.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            '.' if number.contains('.') => {
+                push_number(&mut number, &mut tokens);
+                number.push(c);
+            }
+            '.' => number.push(c),
+            '-' | '+' if !number.ends_with('e') => {
+                push_number(&mut number, &mut tokens);
+                number.push(c);
+            }
+            'e' if !number.is_empty() => number.push(c),
+            ',' | ' ' | '\t' | '\n' | '\r' => push_number(&mut number, &mut tokens),
+            _ => {
+                push_number(&mut number, &mut tokens);
+                tokens.push(c.to_string());
+            }
+        }
+    }
+    push_number(&mut number, &mut tokens);
+
+    tokens.join(" ")
+}
+
+fn diff(path: &str, feather: &Element, rendered: &Element, differences: &mut Vec<String>) {
+    if feather.name != rendered.name {
+        differences.push(format!(
+            "{}: expected <{}> found <{}>",
+            path, feather.name, rendered.name
+        ));
+        return;
+    }
+
+    for (key, value) in feather.attributes.iter() {
+        match rendered.attributes.get(key) {
+            Some(rendered_value) if rendered_value == value => (),
+            Some(rendered_value) => differences.push(format!(
+                "{} @{}:\n      feather:    {}\n      yew_assets: {}",
+                path, key, value, rendered_value
+            )),
+            None => differences.push(format!("{} @{}: missing", path, key)),
+        }
+    }
+
+    for key in rendered.attributes.keys() {
+        if !feather.attributes.contains_key(key) {
+            differences.push(format!("{} @{}: not in feather", path, key));
+        }
+    }
+
+    if feather.children.len() != rendered.children.len() {
+        differences.push(format!(
+            "{}: expected {} children found {}",
+            path,
+            feather.children.len(),
+            rendered.children.len()
+        ));
+    }
+
+    for (index, (feather_child, rendered_child)) in feather
+        .children
+        .iter()
+        .zip(rendered.children.iter())
+        .enumerate()
+    {
+        let child_path = format!("{} > {}[{}]", path, feather_child.name, index);
+        diff(&child_path, feather_child, rendered_child, differences);
+    }
+}
+
+fn assert_no_differences(differences: Vec<(String, Vec<String>)>) {
+    let report: Vec<String> = differences
+        .into_iter()
+        .filter(|(_, differences)| !differences.is_empty())
+        .map(|(name, differences)| format!("{}:\n    {}", name, differences.join("\n    ")))
+        .collect();
+
+    assert!(
+        report.is_empty(),
+        "{} icons differ from feather:\n\n{}",
+        report.len(),
+        report.join("\n\n")
+    );
+}
+
+#[test]
+fn should_render_the_root_attributes_of_feather() {
+    let feather_root = Element {
+        name: "svg".to_string(),
+        attributes: FEATHER_ROOT
+            .iter()
+            .map(|(key, value)| (key.to_string(), normalise(value)))
+            .collect(),
+        children: vec![],
+    };
+
+    let differences = Icon::all()
+        .iter()
+        .map(|icon| {
+            let mut differences = vec![];
+            match parse(&render(icon)) {
+                Ok(mut rendered) => {
+                    rendered.children.clear();
+                    diff("svg", &feather_root, &rendered, &mut differences);
+                }
+                Err(e) => differences.push(format!("invalid svg: {}", e)),
+            }
+            (icon.name().to_string(), differences)
+        })
+        .collect();
+
+    assert_no_differences(differences);
+}
+
+#[test]
+fn should_have_unique_names() {
+    let mut names: Vec<&str> = Icon::all().iter().map(|icon| icon.name()).collect();
+    let total = names.len();
+    names.sort_unstable();
+    names.dedup();
+
+    assert_eq!(names.len(), total);
+}
+
+/// Compare every icon with the svg vendored from feather in `tests/feather`,
+/// run `tests/feather/update.sh` to vendor them
+#[test]
+fn should_render_the_same_elements_of_feather() {
+    let feather_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/feather");
+    let vendored = fs::read_dir(&feather_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .any(|entry| entry.path().extension() == Some("svg".as_ref()));
+
+    assert!(
+        vendored,
+        "feather sources are not vendored, run {}/update.sh to compare the icons",
+        feather_dir.display()
+    );
+
+    let differences = Icon::all()
+        .iter()
+        .map(|icon| {
+            let mut differences = vec![];
+            let path = feather_dir.join(format!("{}.svg", icon.name()));
+
+            match (fs::read_to_string(&path), parse(&render(icon))) {
+                (Ok(feather), Ok(rendered)) => match parse(&feather) {
+                    Ok(feather) => diff("svg", &feather, &rendered, &mut differences),
+                    Err(e) => differences.push(format!("invalid feather svg: {}", e)),
+                },
+                (Err(_), _) => differences.push(format!("{} not found", path.display())),
+                (_, Err(e)) => differences.push(format!("invalid svg: {}", e)),
+            }
+
+            (icon.name().to_string(), differences)
+        })
+        .collect();
+
+    assert_no_differences(differences);
+}
@@ -0,0 +1,10 @@
+#!/bin/sh
+# Vendor the svgs of feather used by tests/conformance.rs
+set -e
+
+VERSION=${1:-4.28.0}
+
+cd "$(dirname "$0")"
+rm -f ./*.svg
+curl -sL "https://registry.npmjs.org/feather-icons/-/feather-icons-$VERSION.tgz" \
+    | tar xz --strip-components=3 package/dist/icons
@@ -0,0 +1,91 @@
+#![cfg(feature = "full")]
+
+use yew_assets::file_assets::FileIcon;
+use yew_assets::{file_icon_for, set_file_icon_table, update_file_icon_table, FileIconTable};
+
+#[test]
+fn should_get_the_icon_of_the_extension() {
+    for (path, name) in [
+        ("report.pdf", "file-text"),
+        ("photos/Holidays.JPG", "image"),
+        ("C:\\music\\song.flac", "music"),
+        ("src/main.rs", "code"),
+        ("backup.tar.gz", "archive"),
+        ("Dockerfile", "terminal"),
+        ("budget.xlsx", "grid"),
+        ("notes", "file"),
+        (".gitignore", "file"),
+        ("assets/", "folder"),
+    ]
+    .iter()
+    {
+        assert_eq!(file_icon_for(path).name(), *name, "{}", path);
+    }
+}
+
+#[test]
+fn should_get_the_icon_of_the_paths_named_like_mime_types() {
+    for (path, name) in [
+        ("text/main.rs", "code"),
+        ("text/notes", "file-text"),
+        ("audio/logo.png", "image"),
+        ("image/old/logo.png", "image"),
+        ("video/backup.zip", "archive"),
+    ]
+    .iter()
+    {
+        assert_eq!(file_icon_for(path).name(), *name, "{}", path);
+    }
+}
+
+#[test]
+fn should_get_the_icon_of_the_mime_type() {
+    for (mime_type, name) in [
+        ("image/png", "image"),
+        ("video/mp4", "film"),
+        ("text/plain; charset=utf-8", "file-text"),
+        ("application/json", "code"),
+        ("application/zip", "archive"),
+        ("inode/directory", "folder"),
+        ("application/octet-stream", "file"),
+        ("application/report.pdf", "file-text"),
+        ("image/svg+xml", "image"),
+    ]
+    .iter()
+    {
+        assert_eq!(file_icon_for(mime_type).name(), *name, "{}", mime_type);
+    }
+}
+
+#[test]
+fn should_override_the_table() {
+    let mut table = FileIconTable::default();
+    table.insert_extension(".PDF", FileIcon::FilePlus);
+    table.insert_mime_type("image/*", FileIcon::FileMinus);
+    table.file = FileIcon::FileText.into();
+
+    assert_eq!(table.icon_for("report.pdf").name(), "file-plus");
+    assert_eq!(table.icon_for("image/webp").name(), "file-minus");
+    assert_eq!(table.icon_for("unknown.xyz").name(), "file-text");
+    assert_eq!(file_icon_for("report.pdf").name(), "file-text");
+}
+
+#[test]
+fn should_change_the_default_table() {
+    update_file_icon_table(|table| {
+        table.insert_extension("log", FileIcon::FileMinus);
+        table.insert_mime_type("application/vnd.acme+json", FileIcon::FilePlus);
+    });
+
+    assert_eq!(file_icon_for("server.log").name(), "file-minus");
+    assert_eq!(
+        file_icon_for("application/vnd.acme+json").name(),
+        "file-plus"
+    );
+    assert_eq!(file_icon_for("report.pdf").name(), "file-text");
+
+    set_file_icon_table(FileIconTable::empty());
+
+    assert_eq!(file_icon_for("server.log").name(), "file");
+    assert_eq!(file_icon_for("assets/").name(), "folder");
+}
@@ -0,0 +1,103 @@
+#![cfg(all(feature = "font", feature = "full"))]
+
+use brotli::Decompressor;
+use read_fonts::types::{GlyphId, Tag};
+use read_fonts::{FontRef, TableProvider};
+use std::io::Read;
+use yew_assets::communication_assets::CommunicationIcon;
+use yew_assets::ux_assets::UxIcon;
+use yew_assets::{Icon, IconFont};
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+fn read_base_128(bytes: &[u8], offset: &mut usize) -> u32 {
+    let mut value = 0;
+    loop {
+        let byte = bytes[*offset];
+        *offset += 1;
+        value = (value << 7) | (byte & 0x7f) as u32;
+        if byte & 0x80 == 0 {
+            return value;
+        }
+    }
+}
+
+#[test]
+fn should_map_every_icon_to_a_glyph() {
+    let icons = vec![
+        Icon::from(UxIcon::ShieldOff),
+        Icon::from(CommunicationIcon::Star),
+    ];
+    let ttf = IconFont::new(icons.clone()).to_ttf();
+    let font = FontRef::new(&ttf).unwrap();
+    let cmap = font.cmap().unwrap();
+    let loca = font.loca(None).unwrap();
+    let glyf = font.glyf().unwrap();
+
+    assert_eq!(font.maxp().unwrap().num_glyphs(), 3);
+
+    for (index, icon) in icons.iter().enumerate() {
+        let glyph_id = cmap.map_codepoint(icon.codepoint()).unwrap();
+
+        assert_eq!(glyph_id, GlyphId::new(index as u32 + 1));
+        assert!(loca.get_glyf(glyph_id, &glyf).unwrap().is_some());
+    }
+}
+
+#[test]
+fn should_keep_codepoints_stable() {
+    assert_eq!(Icon::from(UxIcon::ShieldOff).codepoint(), '\u{ee00}');
+    assert_eq!(Icon::from(UxIcon::Archive).codepoint(), '\u{ee01}');
+    assert_eq!(Icon::from(CommunicationIcon::Star).codepoint(), '\u{e208}');
+}
+
+#[test]
+fn should_wrap_the_ttf_tables_in_woff2() {
+    let font = IconFont::new(Icon::all());
+    let ttf = font.to_ttf();
+    let woff2 = font.to_woff2();
+
+    assert_eq!(&woff2[0..4], b"wOF2");
+    assert_eq!(read_u32(&woff2, 8) as usize, woff2.len());
+
+    let num_tables = u16::from_be_bytes([woff2[12], woff2[13]]) as usize;
+    let mut offset = 48;
+    let mut lengths = vec![];
+
+    for _ in 0..num_tables {
+        if woff2[offset] & 0x3f == 63 {
+            offset += 4;
+        }
+        offset += 1;
+        lengths.push(read_base_128(&woff2, &mut offset) as usize);
+    }
+
+    let mut data = vec![];
+    Decompressor::new(&woff2[offset..], 4096)
+        .read_to_end(&mut data)
+        .unwrap();
+
+    assert_eq!(data.len(), lengths.iter().sum::<usize>());
+
+    let ttf_font = FontRef::new(&ttf).unwrap();
+    let glyf = ttf_font.table_data(Tag::new(b"glyf")).unwrap();
+
+    assert!(data
+        .windows(glyf.len())
+        .any(|window| window == glyf.as_bytes()));
+}
+
+#[test]
+fn should_add_a_css_class_for_every_icon() {
+    let css = IconFont::new(vec![Icon::from(UxIcon::ShieldOff)]).to_css("feather.woff2");
+
+    assert!(css.contains("src: url(\"feather.woff2\") format(\"woff2\");"));
+    assert!(css.contains(".feather-shield-off::before {\n  content: \"\\ee00\";\n}"));
+}
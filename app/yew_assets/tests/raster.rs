@@ -0,0 +1,115 @@
+#![cfg(all(feature = "raster", feature = "full"))]
+
+use resvg::tiny_skia::Pixmap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use yew_assets::communication_assets::CommunicationIcon;
+use yew_assets::controller_assets::ControllerIcon;
+use yew_assets::dev_assets::DevIcon;
+use yew_assets::editing_assets::EditingIcon;
+use yew_assets::ux_assets::UxIcon;
+use yew_assets::{render_png, Favicon, Icon};
+
+// Set UPDATE_SNAPSHOTS=1 to write the snapshots again after an intended change
+fn assert_snapshot(name: &str, png: Vec<u8>) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}.png", name));
+
+    if env::var("UPDATE_SNAPSHOTS").is_ok() {
+        fs::write(&path, &png).unwrap();
+        return;
+    }
+
+    let expected = Pixmap::decode_png(&fs::read(&path).unwrap()).unwrap();
+    let rendered = Pixmap::decode_png(&png).unwrap();
+
+    assert_eq!(
+        (rendered.width(), rendered.height()),
+        (expected.width(), expected.height()),
+        "size of {} differs from its snapshot",
+        name
+    );
+    assert!(
+        rendered.data() == expected.data(),
+        "pixels of {} differ from its snapshot",
+        name
+    );
+}
+
+#[test]
+fn should_render_terminal_as_favicon() {
+    let png = render_png(&Icon::from(DevIcon::Terminal), 32, "#333");
+
+    assert_snapshot("terminal_32_333", png);
+}
+
+#[test]
+fn should_render_star_with_colour() {
+    let png = render_png(&Icon::from(CommunicationIcon::Star), 48, "#ff9900");
+
+    assert_snapshot("star_48_ff9900", png);
+}
+
+#[test]
+fn should_render_shield_off_in_large_size() {
+    let png = render_png(&Icon::from(UxIcon::ShieldOff), 192, "purple");
+
+    assert_snapshot("shield_off_192_purple", png);
+}
+
+#[test]
+fn should_render_png_with_the_requested_size() {
+    for size in [16, 24, 180, 512].iter() {
+        let png = render_png(&Icon::from(ControllerIcon::ArrowRight), *size, "#000");
+        let pixmap = Pixmap::decode_png(&png).unwrap();
+
+        assert_eq!((pixmap.width(), pixmap.height()), (*size, *size));
+    }
+}
+
+#[test]
+fn should_paint_the_stroke_with_the_colour() {
+    let png = render_png(&Icon::from(EditingIcon::Minus), 24, "#ff0000");
+    let pixmap = Pixmap::decode_png(&png).unwrap();
+    let center = pixmap.pixel(12, 12).unwrap();
+
+    assert_eq!(
+        (center.red(), center.green(), center.blue(), center.alpha()),
+        (255, 0, 0, 255)
+    );
+}
+
+#[test]
+fn should_generate_the_favicon_set() {
+    let mut favicon = Favicon::new(DevIcon::Terminal);
+    favicon.foreground = "#fff".to_string();
+    favicon.background = "#1e1e1e".to_string();
+    favicon.corner_radius = 0.2;
+
+    let files = favicon.files();
+    let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+
+    assert_eq!(
+        names,
+        vec![
+            "favicon.ico",
+            "favicon-16x16.png",
+            "favicon-32x32.png",
+            "apple-touch-icon.png",
+            "icon-192.png",
+            "icon-512.png",
+            "icon-192-maskable.png",
+            "icon-512-maskable.png",
+        ]
+    );
+
+    let ico = &files[0].1;
+    // reserved, icon type and the 16, 32 and 48 images
+    assert_eq!(&ico[0..6], &[0, 0, 1, 0, 3, 0]);
+    assert_eq!((ico[6], ico[22], ico[38]), (16, 32, 48));
+
+    assert_snapshot("favicon_terminal_192", files[4].1.clone());
+    assert_snapshot("favicon_terminal_192_maskable", files[6].1.clone());
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "full")]
+
+use yew_assets::{is_daytime, WeatherCondition};
+
+const SUNRISE: i64 = 1_600_000_000;
+const SUNSET: i64 = SUNRISE + 12 * 60 * 60;
+
+#[test]
+fn should_map_the_wmo_codes() {
+    for (code, condition) in [
+        (0, WeatherCondition::Clear),
+        (2, WeatherCondition::PartlyCloudy),
+        (45, WeatherCondition::Fog),
+        (55, WeatherCondition::Drizzle),
+        (66, WeatherCondition::FreezingRain),
+        (81, WeatherCondition::Rain),
+        (86, WeatherCondition::Snow),
+        (99, WeatherCondition::Thunderstorm),
+    ]
+    .iter()
+    {
+        assert_eq!(WeatherCondition::from_wmo_code(*code), Some(*condition));
+    }
+
+    assert_eq!(WeatherCondition::from_wmo_code(4), None);
+}
+
+#[test]
+fn should_map_the_openweather_ids() {
+    for (id, condition) in [
+        (211, WeatherCondition::Thunderstorm),
+        (301, WeatherCondition::Drizzle),
+        (511, WeatherCondition::FreezingRain),
+        (522, WeatherCondition::Rain),
+        (613, WeatherCondition::Sleet),
+        (601, WeatherCondition::Snow),
+        (741, WeatherCondition::Fog),
+        (781, WeatherCondition::Wind),
+        (800, WeatherCondition::Clear),
+        (804, WeatherCondition::Cloudy),
+    ]
+    .iter()
+    {
+        assert_eq!(WeatherCondition::from_openweather_id(*id), Some(*condition));
+    }
+
+    assert_eq!(WeatherCondition::from_openweather_id(900), None);
+}
+
+#[test]
+fn should_use_the_sun_or_the_moon_by_the_time_of_the_day() {
+    let clear = WeatherCondition::Clear;
+
+    assert_eq!(
+        clear.icon_at(SUNRISE + 3 * 60 * 60, SUNRISE, SUNSET).name(),
+        "sun"
+    );
+    assert_eq!(
+        clear.icon_at(SUNSET + 3 * 60 * 60, SUNRISE, SUNSET).name(),
+        "moon"
+    );
+    assert_eq!(
+        clear.icon_at(SUNRISE - 10 * 60, SUNRISE, SUNSET).name(),
+        "sunrise"
+    );
+    assert_eq!(
+        clear.icon_at(SUNSET + 10 * 60, SUNRISE, SUNSET).name(),
+        "sunset"
+    );
+    assert_eq!(
+        WeatherCondition::Rain
+            .icon_at(SUNRISE, SUNRISE, SUNSET)
+            .name(),
+        "cloud-rain"
+    );
+    assert!(is_daytime(SUNRISE, SUNRISE, SUNSET));
+    assert!(!is_daytime(SUNSET, SUNRISE, SUNSET));
+}
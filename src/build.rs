@@ -0,0 +1,206 @@
+use crate::config::Config;
+use serde::Serialize;
+use serde_json::Value;
+use std::process::Stdio;
+use std::time::Instant;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildStatus {
+    Success,
+    Failed,
+}
+
+/// Error or warning of rustc, taken from the primary span of the message
+#[derive(Clone, Debug, Serialize)]
+pub struct Diagnostic {
+    pub level: String,
+    pub message: String,
+    pub file: Option<String>,
+    pub line: Option<u64>,
+    pub column: Option<u64>,
+    pub rendered: Option<String>,
+}
+
+/// Result of a wasm-pack build
+#[derive(Clone, Debug, Serialize)]
+pub struct BuildReport {
+    pub status: BuildStatus,
+    pub duration_ms: u64,
+    pub diagnostics: Vec<Diagnostic>,
+    /// Output of wasm-pack in the failed builds, useful when rustc has no diagnostics
+    pub output: Vec<String>,
+}
+
+impl BuildReport {
+    pub fn is_success(&self) -> bool {
+        self.status == BuildStatus::Success
+    }
+
+    pub fn log(&self) {
+        for diagnostic in &self.diagnostics {
+            match &diagnostic.rendered {
+                Some(rendered) if diagnostic.level == "error" => error!("{}", rendered),
+                Some(rendered) => warn!("{}", rendered),
+                None => (),
+            }
+        }
+
+        if self.is_success() {
+            info!("build finished in {}ms", self.duration_ms);
+        } else {
+            error!("build failed in {}ms", self.duration_ms);
+        }
+    }
+}
+
+/// Build the wasm package with wasm-pack. Dropping the future before it
+/// finishes cancels the build, killing wasm-pack and its cargo and rustc processes
+pub async fn build_wasm(config: &Config) -> BuildReport {
+    let start = Instant::now();
+    let mut cmd = Command::new("wasm-pack");
+    let cmd = cmd
+        .arg("build")
+        .arg(config.profile.flag())
+        .args(["--target", "web"])
+        .args(["--", "--message-format=json"])
+        .current_dir(&config.wasm_path);
+
+    if !config.features.is_empty() {
+        cmd.args(["--features", &config.features.join(",")]);
+    }
+
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    // own process group to kill cargo and rustc with wasm-pack
+    #[cfg(unix)]
+    cmd.process_group(0);
+
+    let mut diagnostics = vec![];
+    let mut output = vec![];
+
+    let status = match cmd.spawn() {
+        Ok(mut child) => {
+            let mut guard = BuildGuard { pid: child.id() };
+            let stdout = child
+                .stdout
+                .take()
+                .expect("child did not have a handle to stdout");
+            let stderr = child
+                .stderr
+                .take()
+                .expect("child did not have a handle to stderr");
+
+            let read_stdout = async {
+                let mut reader = BufReader::new(stdout).lines();
+
+                while let Ok(Some(line)) = reader.next_line().await {
+                    match serde_json::from_str::<Value>(&line) {
+                        Ok(message) => diagnostics.extend(get_diagnostic(&message)),
+                        Err(_) => info!("{}", line),
+                    }
+                }
+            };
+
+            let read_stderr = async {
+                let mut reader = BufReader::new(stderr).lines();
+
+                while let Ok(Some(line)) = reader.next_line().await {
+                    info!("{}", line);
+                    output.push(line);
+                }
+            };
+
+            tokio::join!(read_stdout, read_stderr);
+
+            let status = match child.wait().await {
+                Ok(exit_status) if exit_status.success() => BuildStatus::Success,
+                Ok(_) => BuildStatus::Failed,
+                Err(e) => {
+                    output.push(e.to_string());
+                    BuildStatus::Failed
+                }
+            };
+
+            guard.pid = None;
+            status
+        }
+        Err(e) => {
+            output.push(format!("failed to spawn wasm-pack: {}", e));
+            BuildStatus::Failed
+        }
+    };
+
+    if status == BuildStatus::Success {
+        output.clear();
+    }
+
+    BuildReport {
+        status,
+        duration_ms: start.elapsed().as_millis() as u64,
+        diagnostics,
+        output,
+    }
+}
+
+/// Diagnostic of a cargo `compiler-message`, if it's an error or a warning
+fn get_diagnostic(message: &Value) -> Option<Diagnostic> {
+    if message["reason"] != "compiler-message" {
+        return None;
+    }
+
+    let message = &message["message"];
+    let level = message["level"].as_str()?;
+
+    if level != "error" && level != "warning" {
+        return None;
+    }
+
+    let span = message["spans"]
+        .as_array()
+        .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true));
+
+    Some(Diagnostic {
+        level: level.to_string(),
+        message: message["message"].as_str().unwrap_or_default().to_string(),
+        file: span.and_then(|span| span["file_name"].as_str().map(String::from)),
+        line: span.and_then(|span| span["line_start"].as_u64()),
+        column: span.and_then(|span| span["column_start"].as_u64()),
+        rendered: message["rendered"].as_str().map(String::from),
+    })
+}
+
+/// Kill the processes of a build which didn't finish
+struct BuildGuard {
+    pid: Option<u32>,
+}
+
+impl Drop for BuildGuard {
+    fn drop(&mut self) {
+        if let Some(pid) = self.pid {
+            info!("cancel build");
+            kill_process_tree(pid);
+        }
+    }
+}
+
+#[cfg(unix)]
+fn kill_process_tree(pid: u32) {
+    // the negative pid sends the signal to all the process group
+    if unsafe { libc::kill(-(pid as i32), libc::SIGKILL) } != 0 {
+        warn!("failed to kill build {}", std::io::Error::last_os_error());
+    }
+}
+
+#[cfg(windows)]
+fn kill_process_tree(pid: u32) {
+    if let Err(e) = std::process::Command::new("taskkill")
+        .args(["/T", "/F", "/PID", &pid.to_string()])
+        .output()
+    {
+        warn!("failed to kill build {}", e);
+    }
+}
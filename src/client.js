@@ -0,0 +1,161 @@
+// Live reload client of the yew_assets dev server, injected in the served html pages
+(() => {
+  const VERSION = "__VERSION__";
+  let connected = false;
+
+  // errors shown in the overlay until they are fixed, the build ones first
+  let failedBuild = null;
+  const sassErrors = new Map();
+
+  const getBuildSection = (report) => {
+    const errors = report.diagnostics
+      .filter((diagnostic) => diagnostic.level === "error")
+      .map((diagnostic) => ({
+        location: diagnostic.file
+          ? `${diagnostic.file}:${diagnostic.line}:${diagnostic.column}`
+          : diagnostic.message,
+        text: diagnostic.rendered || diagnostic.message,
+      }));
+
+    return {
+      title: `Build failed in ${report.duration_ms}ms`,
+      entries: errors.length > 0 ? errors : [{ text: report.output.join("\n") }],
+    };
+  };
+
+  const renderOverlay = () => {
+    const current = document.getElementById("yew-build-overlay");
+
+    if(current) {
+      current.remove();
+    }
+
+    const sections = [];
+
+    if(failedBuild) {
+      sections.push(getBuildSection(failedBuild));
+    }
+
+    if(sassErrors.size > 0) {
+      sections.push({
+        title: "Sass compilation failed",
+        entries: Array.from(sassErrors, ([path, error]) => ({ location: path, text: error })),
+      });
+    }
+
+    if(sections.length === 0) {
+      return;
+    }
+
+    const overlay = document.createElement("div");
+    overlay.id = "yew-build-overlay";
+    overlay.style.cssText = "position: fixed; inset: 0; z-index: 2147483647; overflow: auto; padding: 2rem; background: rgba(0, 0, 0, 0.9); color: #e8e8e8; font: 14px/1.5 monospace;";
+
+    sections.forEach((section) => {
+      const title = document.createElement("h2");
+      title.style.cssText = "margin: 0 0 1rem; color: #ff5555;";
+      title.textContent = section.title;
+      overlay.appendChild(title);
+
+      section.entries.forEach((entry) => {
+        if(entry.location) {
+          const location = document.createElement("div");
+          location.style.cssText = "color: #ffb86c;";
+          location.textContent = entry.location;
+          overlay.appendChild(location);
+        }
+
+        const text = document.createElement("pre");
+        text.style.cssText = "margin: 0.5rem 0 1.5rem; white-space: pre-wrap;";
+        text.textContent = entry.text;
+        overlay.appendChild(text);
+      });
+    });
+
+    document.body.appendChild(overlay);
+  };
+
+  const matchesPath = (url, path) => {
+    return url && new URL(url, window.location.href).pathname === `/${path}`;
+  };
+
+  const swapStylesheet = (path) => {
+    const links = Array.from(document.querySelectorAll('link[rel="stylesheet"]'))
+      .filter((link) => matchesPath(link.href, path));
+
+    links.forEach((link) => {
+      const swap = link.cloneNode();
+      swap.href = `/${path}?t=${Date.now()}`;
+      // remove the old stylesheet once the new one is loaded to avoid a flash without styles
+      swap.onload = () => link.remove();
+      link.after(swap);
+    });
+  };
+
+  const refreshAsset = (path) => {
+    document.querySelectorAll("[src], link[href]").forEach((element) => {
+      const attribute = element.hasAttribute("src") ? "src" : "href";
+
+      if(matchesPath(element.getAttribute(attribute), path)) {
+        element.setAttribute(attribute, `/${path}?t=${Date.now()}`);
+      }
+    });
+  };
+
+  const connect = () => {
+    const protocol = window.location.protocol === "https:" ? "wss:" : "ws:";
+    const reloadSocket = new WebSocket(`${protocol}//${window.location.host}/__RELOAD_PATH__`);
+
+    reloadSocket.onopen = (event) => {
+      reloadSocket.send(`${navigator.userAgent} connected with client ${VERSION}`);
+    }
+
+    reloadSocket.onmessage = (event) => {
+      const message = JSON.parse(event.data);
+
+      if(message.type === "connected") {
+        // the server restarted since the last connection
+        if(connected) {
+          window.location.reload();
+        }
+        connected = true;
+      } else if(message.type === "build") {
+        if(message.status === "success") {
+          failedBuild = null;
+          renderOverlay();
+          window.location.reload();
+        } else {
+          failedBuild = message;
+          renderOverlay();
+        }
+      } else if(message.type === "reload") {
+        window.location.reload();
+      } else if(message.type === "sass") {
+        if(message.error) {
+          sassErrors.set(message.path, message.error);
+        } else {
+          sassErrors.delete(message.path);
+        }
+        renderOverlay();
+      } else if(message.type === "css-update") {
+        swapStylesheet(message.path);
+      } else if(message.type === "asset-update") {
+        refreshAsset(message.path);
+      }
+    };
+
+    reloadSocket.onclose = (e) => {
+      console.log('Socket is closed. Reconnect will be attempted in 1 second.', e.reason);
+      setTimeout(() => {
+        connect();
+      }, 1000);
+    };
+
+    reloadSocket.onerror = (error) => {
+      console.error(`error: ${error}`);
+      reloadSocket.close();
+    }
+  }
+
+  connect();
+})();
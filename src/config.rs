@@ -0,0 +1,398 @@
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::time::Duration;
+
+/// Config file read from the working directory when `--config` is not set
+pub const CONFIG_FILE: &str = "yew_assets.toml";
+
+pub const USAGE: &str = "Usage:
+    yew_assets [serve] [options]    build, watch and serve the app with live reload
+    yew_assets build [options]      compile the Sass files and build the app once
+    yew_assets watch [options]      build the app again when the sources change
+
+Options:
+    --config <file>         config file, by default yew_assets.toml if it exists
+    --address <address>     address of the server, like 127.0.0.1:8080
+    --port <port>           port of the server, replacing the port of the address
+    --wasm-path <path>      directory of the app
+    --profile <profile>     dev, release or profiling
+    --features <features>   cargo features of the app separated by commas
+    --watch <paths>         directories to watch separated by commas, by default the app
+    --include <patterns>    gitignore-style patterns of the only files to watch
+    --ignore <patterns>     gitignore-style patterns of the files to not watch
+    --no-gitignore          don't read the .gitignore and .ignore files
+    --no-inject             don't inject the live reload client in the html pages
+    --help                  show this help
+
+The options take precedence over the environment variables, and they over the config file";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Serve,
+    Build,
+    Watch,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Profile {
+    #[default]
+    Dev,
+    Release,
+    Profiling,
+}
+
+impl Profile {
+    fn parse(profile: &str) -> Result<Profile, String> {
+        match profile {
+            "dev" | "development" => Ok(Profile::Dev),
+            "release" | "production" => Ok(Profile::Release),
+            "profiling" => Ok(Profile::Profiling),
+            _ => Err(format!("unknown profile {}", profile)),
+        }
+    }
+
+    /// wasm-pack flag of the profile
+    pub fn flag(&self) -> &'static str {
+        match self {
+            Profile::Dev => "--dev",
+            Profile::Release => "--release",
+            Profile::Profiling => "--profiling",
+        }
+    }
+}
+
+/// Settings of the dev server
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Address of the server
+    pub address: String,
+    /// Port of the server, replacing the port of the address
+    pub port: Option<u16>,
+    /// Directory of the app, with its index.html, static files and wasm package
+    pub wasm_path: String,
+    pub profile: Profile,
+    /// Cargo features to build the app with
+    pub features: Vec<String>,
+    /// Directories which are watched, the directory of the app when it's empty
+    pub watch: Vec<String>,
+    /// Gitignore-style patterns of the only files which are watched, all of them when it's empty
+    pub include: Vec<String>,
+    /// Gitignore-style patterns of the files and directories which are not watched
+    pub ignore: Vec<String>,
+    /// Don't watch the files ignored by the `.gitignore` and `.ignore` files either
+    pub gitignore: bool,
+    /// Inject the live reload client in the served html pages
+    pub inject_client: bool,
+    /// Milliseconds without file events before the changes are reported
+    pub debounce_ms: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            address: "127.0.0.1:8080".to_string(),
+            port: None,
+            wasm_path: "app".to_string(),
+            profile: Profile::Dev,
+            features: vec![],
+            watch: vec![],
+            include: vec![],
+            ignore: vec![
+                ".git".to_string(),
+                "target".to_string(),
+                "pkg".to_string(),
+                "node_modules".to_string(),
+                "Cargo.lock".to_string(),
+            ],
+            gitignore: true,
+            inject_client: true,
+            debounce_ms: 200,
+        }
+    }
+}
+
+impl Config {
+    /// Command and config of the command line arguments, the environment and the config file
+    pub fn load(args: Vec<String>) -> Result<(Command, Config), String> {
+        Self::load_with(args, |name| env::var(name).ok())
+    }
+
+    /// Command and config with the environment variables of `var`
+    fn load_with(
+        args: Vec<String>,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<(Command, Config), String> {
+        let mut args = args.into_iter().peekable();
+
+        let command = match args.next_if(|arg| !arg.starts_with("--")) {
+            None => Command::Serve,
+            Some(command) => match command.as_str() {
+                "serve" => Command::Serve,
+                "build" => Command::Build,
+                "watch" => Command::Watch,
+                _ => return Err(format!("unknown command {}\n\n{}", command, USAGE)),
+            },
+        };
+
+        let mut options = vec![];
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--no-inject" | "--no-gitignore" => options.push((arg, String::new())),
+                _ if arg.starts_with("--") => match args.next() {
+                    Some(value) => options.push((arg, value)),
+                    None => return Err(format!("missing value of {}", arg)),
+                },
+                _ => return Err(format!("unexpected argument {}\n\n{}", arg, USAGE)),
+            }
+        }
+
+        let config_file = options
+            .iter()
+            .find(|(option, _)| option == "--config")
+            .map(|(_, value)| value.clone());
+
+        let mut config = Config::from_file(config_file.or_else(|| var("YEW_ASSETS_CONFIG")))?;
+        config.apply_env(&var)?;
+
+        // the port of each source replaces the port of its own address, so it's applied after it
+        options.sort_by_key(|(option, _)| option == "--port");
+
+        for (option, value) in options {
+            config.apply_option(&option, value)?;
+        }
+
+        Ok((command, config))
+    }
+
+    fn from_file(path: Option<String>) -> Result<Config, String> {
+        let path = match path {
+            Some(path) => path,
+            None if Path::new(CONFIG_FILE).exists() => CONFIG_FILE.to_string(),
+            None => return Ok(Config::default()),
+        };
+
+        let content = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?;
+        toml::from_str(&content).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), String> {
+        if let Some(address) = var("SERVER_ADDRESS") {
+            self.apply_option("--address", address)?;
+        } else if let Some(address) = var("SERVER_ADRESS") {
+            warn!("SERVER_ADRESS is deprecated, use SERVER_ADDRESS");
+            self.apply_option("--address", address)?;
+        }
+        if let Some(port) = var("SERVER_PORT") {
+            self.apply_option("--port", port)?;
+        }
+        if let Some(wasm_path) = var("WASM_PATH") {
+            self.wasm_path = wasm_path;
+        }
+        if let Some(env_build) = var("ENV_BUILD") {
+            self.profile = Profile::parse(&env_build)?;
+        }
+        if let Some(features) = var("FEATURES") {
+            self.features = split_list(&features);
+        }
+        if let Some(watch_paths) = var("WATCH_PATHS") {
+            self.watch = split_list(&watch_paths);
+        }
+        if let Some(including_paths) = var("INCLUDING_PATHS") {
+            self.include = split_list(&including_paths);
+        }
+        if let Some(excluding_paths) = var("EXCLUDING_PATHS") {
+            self.ignore = split_list(&excluding_paths);
+        }
+        if let Some(inject_client) = var("INJECT_CLIENT") {
+            self.inject_client = inject_client != "false";
+        }
+        if let Some(gitignore) = var("GITIGNORE") {
+            self.gitignore = gitignore != "false";
+        }
+
+        Ok(())
+    }
+
+    fn apply_option(&mut self, option: &str, value: String) -> Result<(), String> {
+        match option {
+            "--config" => (),
+            "--address" => {
+                // the port of the lower sources doesn't replace the port of a new address
+                self.address = value;
+                self.port = None;
+            }
+            "--port" => {
+                self.port = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid port {}", value))?,
+                )
+            }
+            "--wasm-path" => self.wasm_path = value,
+            "--profile" => self.profile = Profile::parse(&value)?,
+            "--features" => self.features = split_list(&value),
+            "--watch" => self.watch = split_list(&value),
+            "--include" => self.include = split_list(&value),
+            "--ignore" => self.ignore = split_list(&value),
+            "--no-inject" => self.inject_client = false,
+            "--no-gitignore" => self.gitignore = false,
+            _ => return Err(format!("unknown option {}\n\n{}", option, USAGE)),
+        }
+
+        Ok(())
+    }
+
+    pub fn server_address(&self) -> Result<SocketAddr, String> {
+        let mut address: SocketAddr = self
+            .address
+            .parse()
+            .map_err(|_| format!("invalid address {}", self.address))?;
+
+        if let Some(port) = self.port {
+            address.set_port(port);
+        }
+
+        Ok(address)
+    }
+
+    /// Watched directories, the directory of the app by default
+    pub fn watch_paths(&self) -> Vec<String> {
+        if self.watch.is_empty() {
+            vec![self.wasm_path.clone()]
+        } else {
+            self.watch.clone()
+        }
+    }
+
+    pub fn debounce(&self) -> Duration {
+        Duration::from_millis(self.debounce_ms)
+    }
+}
+
+/// Items separated by commas or spaces, like the ones of the environment variables
+fn split_list(list: &str) -> Vec<String> {
+    list.split([',', ' '])
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::process;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counter to get a config file for every test
+    static CONFIG_ID: AtomicUsize = AtomicUsize::new(0);
+
+    /// Config of the arguments with a config file and the environment variables
+    fn load(file: &str, vars: &[(&str, &str)], args: &[&str]) -> Config {
+        let path = env::temp_dir().join(format!(
+            "yew_assets_config_{}_{}.toml",
+            process::id(),
+            CONFIG_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&path, file).unwrap();
+
+        let vars = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<HashMap<String, String>>();
+        let mut options = vec!["--config".to_string(), path.display().to_string()];
+        options.extend(args.iter().map(|arg| arg.to_string()));
+
+        let (_, config) = Config::load_with(options, |name| vars.get(name).cloned()).unwrap();
+        fs::remove_file(&path).ok();
+
+        config
+    }
+
+    fn port(config: &Config) -> u16 {
+        config.server_address().unwrap().port()
+    }
+
+    #[test]
+    fn should_take_the_options_over_the_env_and_the_file() {
+        let file = "wasm_path = \"file\"\nprofile = \"release\"\nfeatures = [\"a\"]";
+        let vars = [("WASM_PATH", "env"), ("FEATURES", "b,c")];
+
+        let config = load(file, &[], &[]);
+        assert_eq!(config.wasm_path, "file");
+        assert_eq!(config.profile, Profile::Release);
+        assert_eq!(config.features, vec!["a"]);
+
+        let config = load(file, &vars, &[]);
+        assert_eq!(config.wasm_path, "env");
+        assert_eq!(config.profile, Profile::Release);
+        assert_eq!(config.features, vec!["b", "c"]);
+
+        let config = load(file, &vars, &["--wasm-path", "cli", "--profile", "dev"]);
+        assert_eq!(config.wasm_path, "cli");
+        assert_eq!(config.profile, Profile::Dev);
+        assert_eq!(config.features, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn should_take_the_address_and_the_port_of_the_highest_source() {
+        let file = "address = \"127.0.0.1:8000\"\nport = 8001";
+
+        assert_eq!(port(&load(file, &[], &[])), 8001);
+        assert_eq!(
+            port(&load(file, &[("SERVER_ADDRESS", "127.0.0.1:9000")], &[])),
+            9000
+        );
+        assert_eq!(port(&load(file, &[("SERVER_PORT", "9001")], &[])), 9001);
+        assert_eq!(
+            port(&load(
+                file,
+                &[
+                    ("SERVER_ADDRESS", "127.0.0.1:9000"),
+                    ("SERVER_PORT", "9001")
+                ],
+                &[]
+            )),
+            9001
+        );
+        assert_eq!(
+            port(&load(
+                file,
+                &[("SERVER_PORT", "3000")],
+                &["--address", "127.0.0.1:9000"]
+            )),
+            9000
+        );
+        assert_eq!(
+            port(&load(
+                file,
+                &[("SERVER_ADDRESS", "127.0.0.1:9000")],
+                &["--port", "9002"]
+            )),
+            9002
+        );
+        assert_eq!(
+            port(&load(
+                file,
+                &[("SERVER_PORT", "3000")],
+                &["--port", "9003", "--address", "0.0.0.0:9000"]
+            )),
+            9003
+        );
+    }
+
+    #[test]
+    fn should_read_the_flags_of_the_env_and_the_options() {
+        let config = load("", &[("INJECT_CLIENT", "false")], &["--no-gitignore"]);
+
+        assert!(!config.inject_client);
+        assert!(!config.gitignore);
+        assert_eq!(config.address, "127.0.0.1:8080");
+    }
+}
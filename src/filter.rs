@@ -0,0 +1,342 @@
+use crate::config::Config;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Ignore files read in the watched directories and their parents up to the git repository
+const IGNORE_FILES: &[&str] = &[".gitignore", ".ignore"];
+/// Directories never watched, whatever the `ignore` patterns, since the builds write
+/// in them and their changes would start new builds
+const ALWAYS_IGNORED: &[&str] = &[".git", "target", "pkg"];
+
+/// Paths of a watched root which are reported, from the gitignore-style
+/// `include` and `ignore` patterns of the config and the ignore files
+pub struct PathFilter {
+    root: PathBuf,
+    include: Option<Gitignore>,
+    ignore: Gitignore,
+    gitignore: bool,
+    /// Matchers of the ignore files, the outer directories first
+    ignore_files: Vec<Gitignore>,
+}
+
+impl PathFilter {
+    pub fn new(root: &Path, config: &Config) -> Result<Self, String> {
+        let root = root
+            .canonicalize()
+            .map_err(|e| format!("{}: {}", root.display(), e))?;
+        let include = if config.include.is_empty() {
+            None
+        } else {
+            Some(build_patterns(&root, &config.include)?)
+        };
+        let ignore = ALWAYS_IGNORED
+            .iter()
+            .map(|pattern| pattern.to_string())
+            .chain(config.ignore.iter().cloned())
+            .collect::<Vec<String>>();
+        let ignore = build_patterns(&root, &ignore)?;
+
+        let mut filter = Self {
+            root,
+            include,
+            ignore,
+            gitignore: config.gitignore,
+            ignore_files: vec![],
+        };
+        filter.load_ignore_files();
+
+        Ok(filter)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Read the ignore files again, after one of them changed
+    pub fn load_ignore_files(&mut self) {
+        self.ignore_files.clear();
+
+        if !self.gitignore {
+            return;
+        }
+
+        let mut dirs = parent_dirs(&self.root);
+        self.find_dirs(&self.root.clone(), &mut dirs);
+
+        for dir in dirs {
+            for name in IGNORE_FILES {
+                let path = dir.join(name);
+
+                if !path.is_file() {
+                    continue;
+                }
+
+                let (gitignore, error) = Gitignore::new(&path);
+
+                if let Some(e) = error {
+                    warn!("{}: {}", path.display(), e);
+                }
+
+                self.ignore_files.push(gitignore);
+            }
+        }
+    }
+
+    /// Whether the changes of an absolute path under the root are reported.
+    /// The include patterns only apply to the files, the directories are walked anyway
+    pub fn is_watched(&self, path: &Path, is_dir: bool) -> bool {
+        let relative = match path.strip_prefix(&self.root) {
+            Ok(relative) => relative,
+            Err(_) => return false,
+        };
+
+        if self
+            .ignore
+            .matched_path_or_any_parents(relative, is_dir)
+            .is_ignore()
+        {
+            return false;
+        }
+
+        // like git, the patterns of the inner ignore files override the outer ones
+        let mut ignored = false;
+
+        for gitignore in &self.ignore_files {
+            if let Ok(relative) = path.strip_prefix(gitignore.path()) {
+                match gitignore.matched_path_or_any_parents(relative, is_dir) {
+                    Match::Ignore(_) => ignored = true,
+                    Match::Whitelist(_) => ignored = false,
+                    Match::None => (),
+                }
+            }
+        }
+
+        if ignored {
+            return false;
+        }
+
+        match &self.include {
+            Some(include) if !is_dir => include
+                .matched_path_or_any_parents(relative, false)
+                .is_ignore(),
+            _ => true,
+        }
+    }
+
+    /// Directories under the root which aren't ignored, where the ignore files are looked for
+    fn find_dirs(&self, dir: &Path, dirs: &mut Vec<PathBuf>) {
+        dirs.push(dir.to_path_buf());
+
+        if let Ok(entries) = fs::read_dir(dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let relative = path.strip_prefix(&self.root).unwrap_or(&path);
+
+                if path.is_dir()
+                    && !self
+                        .ignore
+                        .matched_path_or_any_parents(relative, true)
+                        .is_ignore()
+                {
+                    self.find_dirs(&path, dirs);
+                }
+            }
+        }
+    }
+}
+
+pub fn is_ignore_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| IGNORE_FILES.contains(&name))
+}
+
+/// Gitignore-style patterns relative to the root
+fn build_patterns(root: &Path, patterns: &[String]) -> Result<Gitignore, String> {
+    let mut builder = GitignoreBuilder::new(root);
+
+    for pattern in patterns {
+        builder
+            .add_line(None, pattern)
+            .map_err(|e| format!("invalid pattern {}: {}", pattern, e))?;
+    }
+
+    builder.build().map_err(|e| e.to_string())
+}
+
+/// Parents of the root up to the root of its git repository, the outer ones first
+fn parent_dirs(root: &Path) -> Vec<PathBuf> {
+    if root.join(".git").exists() {
+        return vec![];
+    }
+
+    let mut dirs = vec![];
+
+    for dir in root.ancestors().skip(1) {
+        dirs.push(dir.to_path_buf());
+
+        if dir.join(".git").exists() {
+            dirs.reverse();
+            return dirs;
+        }
+    }
+
+    // outside of a git repository only the ignore files under the root apply
+    vec![]
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    /// Empty directory with a `.git` directory, so the ignore files of its parents are not read
+    pub(crate) fn temp_root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("yew_assets_{}_{}", name, process::id()));
+        fs::remove_dir_all(&root).ok();
+        fs::create_dir_all(root.join(".git")).unwrap();
+
+        root
+    }
+
+    pub(crate) fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn is_watched(filter: &PathFilter, path: &str) -> bool {
+        let path = filter.root().join(path);
+        filter.is_watched(&path, path.is_dir())
+    }
+
+    #[test]
+    fn should_ignore_the_patterns_of_the_config() {
+        let root = temp_root("filter_ignore");
+        write(&root, "src/main.rs", "");
+        write(&root, "target/debug/main.rs", "");
+        write(&root, "static/main.tmp", "");
+
+        let config = Config {
+            ignore: vec!["target".to_string(), "*.tmp".to_string()],
+            ..Config::default()
+        };
+        let filter = PathFilter::new(&root, &config).unwrap();
+
+        assert!(is_watched(&filter, "src/main.rs"));
+        assert!(!is_watched(&filter, "target"));
+        assert!(!is_watched(&filter, "target/debug/main.rs"));
+        assert!(!is_watched(&filter, "static/main.tmp"));
+        assert!(!filter.is_watched(Path::new("/elsewhere/main.rs"), false));
+    }
+
+    #[test]
+    fn should_always_ignore_the_build_directories() {
+        let root = temp_root("filter_build_dirs");
+        write(&root, "pkg/app.js", "");
+        write(&root, "target/debug/main.rs", "");
+        write(&root, "node_modules/lib/index.js", "");
+
+        let config = Config {
+            ignore: vec!["node_modules".to_string()],
+            ..Config::default()
+        };
+        let filter = PathFilter::new(&root, &config).unwrap();
+
+        assert!(!is_watched(&filter, "pkg/app.js"));
+        assert!(!is_watched(&filter, "target/debug/main.rs"));
+        assert!(!is_watched(&filter, ".git"));
+        assert!(!is_watched(&filter, "node_modules/lib/index.js"));
+    }
+
+    #[test]
+    fn should_only_include_the_files_of_the_patterns() {
+        let root = temp_root("filter_include");
+        write(&root, "src/main.rs", "");
+        write(&root, "src/style.css", "");
+        write(&root, "index.html", "");
+
+        let config = Config {
+            include: vec!["src/**/*.rs".to_string(), "*.html".to_string()],
+            ..Config::default()
+        };
+        let filter = PathFilter::new(&root, &config).unwrap();
+
+        assert!(is_watched(&filter, "src/main.rs"));
+        assert!(is_watched(&filter, "index.html"));
+        assert!(!is_watched(&filter, "src/style.css"));
+        // the directories are walked to find the included files
+        assert!(is_watched(&filter, "src"));
+    }
+
+    #[test]
+    fn should_ignore_the_patterns_of_the_ignore_files() {
+        let root = temp_root("filter_gitignore");
+        write(&root, ".gitignore", "*.log\nbuild/\n");
+        write(&root, ".ignore", "*.bak\n");
+        write(&root, "build/app.js", "");
+        write(&root, "server.log", "");
+        write(&root, "main.bak", "");
+        write(&root, "main.rs", "");
+
+        let filter = PathFilter::new(&root, &Config::default()).unwrap();
+
+        assert!(is_watched(&filter, "main.rs"));
+        assert!(!is_watched(&filter, "server.log"));
+        assert!(!is_watched(&filter, "main.bak"));
+        assert!(!is_watched(&filter, "build"));
+        assert!(!is_watched(&filter, "build/app.js"));
+
+        let config = Config {
+            gitignore: false,
+            ..Config::default()
+        };
+        let filter = PathFilter::new(&root, &config).unwrap();
+
+        assert!(is_watched(&filter, "server.log"));
+        assert!(is_watched(&filter, "build/app.js"));
+    }
+
+    #[test]
+    fn should_override_the_outer_ignore_files() {
+        let root = temp_root("filter_whitelist");
+        write(&root, ".gitignore", "*.log\n");
+        write(&root, "logs/.gitignore", "!keep.log\n");
+        write(&root, "logs/keep.log", "");
+        write(&root, "logs/server.log", "");
+
+        let mut filter = PathFilter::new(&root, &Config::default()).unwrap();
+
+        assert!(is_watched(&filter, "logs/keep.log"));
+        assert!(!is_watched(&filter, "logs/server.log"));
+
+        write(&root, "logs/.gitignore", "");
+        filter.load_ignore_files();
+
+        assert!(!is_watched(&filter, "logs/keep.log"));
+    }
+
+    #[test]
+    fn should_read_the_ignore_files_of_the_parents_in_the_repository() {
+        let repository = temp_root("filter_parents");
+        write(&repository, ".gitignore", "*.log\n");
+        write(&repository, "app/.gitignore", "!app.log\n");
+        write(&repository, "app/app.log", "");
+        write(&repository, "app/server.log", "");
+
+        let filter = PathFilter::new(&repository.join("app"), &Config::default()).unwrap();
+
+        assert!(is_watched(&filter, "app.log"));
+        assert!(!is_watched(&filter, "server.log"));
+    }
+
+    #[test]
+    fn should_tell_the_ignore_files() {
+        assert!(is_ignore_file(Path::new("app/.gitignore")));
+        assert!(is_ignore_file(Path::new(".ignore")));
+        assert!(!is_ignore_file(Path::new("app/.gitignore.bak")));
+    }
+}
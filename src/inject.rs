@@ -0,0 +1,60 @@
+use hyper::body::{to_bytes, Body};
+use hyper::header::{HeaderValue, ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_TYPE};
+use hyper::StatusCode;
+use std::convert::Infallible;
+use warp::reply::{Reply, Response};
+
+/// Version of the client script, to not use the cached script of another version
+pub const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Live reload client script, with the reload websocket path
+pub fn get_client_script(reload_path: &str) -> String {
+    include_str!("client.js")
+        .replace("__VERSION__", CLIENT_VERSION)
+        .replace("__RELOAD_PATH__", reload_path)
+}
+
+/// Add the script tag of the live reload client before the `</body>` of the html
+/// responses, or at the end of the page without it
+pub async fn inject_client(
+    reply: impl Reply,
+    client_url: String,
+    enabled: bool,
+) -> Result<Response, Infallible> {
+    let response = reply.into_response();
+    let is_html = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("text/html"));
+
+    if !enabled || !is_html || response.status() != StatusCode::OK {
+        return Ok(response);
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let html = match to_bytes(body).await {
+        Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+        Err(e) => {
+            warn!("failed to read html {}", e);
+            return Ok(Response::from_parts(parts, Body::empty()));
+        }
+    };
+
+    let script = format!(
+        "<script src=\"{}?v={}\"></script>\n",
+        client_url, CLIENT_VERSION
+    );
+    let html = match html.rfind("</body>").or_else(|| html.rfind("</BODY>")) {
+        Some(index) => format!("{}{}{}", &html[..index], script, &html[index..]),
+        None => format!("{}{}", html, script),
+    };
+
+    // the ranges of the file don't match the page with the script
+    parts.headers.remove(ACCEPT_RANGES);
+    parts
+        .headers
+        .insert(CONTENT_LENGTH, HeaderValue::from(html.len()));
+
+    Ok(Response::from_parts(parts, Body::from(html)))
+}
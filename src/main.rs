@@ -2,56 +2,132 @@ extern crate pretty_env_logger;
 #[macro_use]
 extern crate log;
 
+mod build;
+mod config;
+mod filter;
+mod inject;
+mod reload;
+mod sass;
+mod watcher;
+
+use build::build_wasm;
+use config::{Command, Config, USAGE};
 use dotenv::dotenv;
-use futures::{
-    future::{abortable, join, AbortHandle},
-    FutureExt, SinkExt, StreamExt,
-};
+use filter::PathFilter;
+use futures::{SinkExt, StreamExt};
 use hyper::server::Server;
 use hyper::service::make_service_fn;
-use spielrs_diff::{diff::DirDiff, dir_diff};
+use inject::{get_client_script, inject_client};
+use reload::{ReloadChannel, ReloadMessage};
+use std::collections::BTreeSet;
+use std::convert::Infallible;
 use std::env;
-use std::net::SocketAddr;
-use std::time::Duration;
-use std::{convert::Infallible, process::Stdio};
-use tokio::fs;
-use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::process::Command;
-use tokio::sync::mpsc;
-use tokio::time::sleep;
-use tokio_stream::wrappers::UnboundedReceiverStream;
-use warp::{
-    ws::{Message, WebSocket},
-    Error, Filter,
-};
+use std::path::{Path, PathBuf};
+use std::process;
+use tokio::sync::broadcast::{self, error::RecvError};
+use warp::{ws::WebSocket, Filter};
+use watcher::{change_kind, get_url, ChangeKind, SourceWatcher};
+
+/// Path of the reload websocket, in the same address as the pages
+const RELOAD_PATH: &str = "__yew_reload";
+/// Name of the live reload client script, served under the reload path
+const CLIENT_SCRIPT: &str = "client.js";
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     pretty_env_logger::init();
     dotenv().ok();
 
-    let server_address: SocketAddr = get_server_address().parse().unwrap();
-    let wasm_path = get_wasm_path();
+    let args = env::args().skip(1).collect::<Vec<String>>();
 
-    build_wasm().await.unwrap();
+    if args.iter().any(|arg| arg == "--help") {
+        println!("{}", USAGE);
+        return Ok(());
+    }
 
-    let websocket_routes = warp::ws().map(|ws: warp::ws::Ws| ws.on_upgrade(connect));
+    let (command, config) = match Config::load(args) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
 
-    let websocket_service = warp::service(websocket_routes);
+    let app = match PathFilter::new(Path::new(&config.wasm_path), &config) {
+        Ok(app) => app,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
 
-    let make_websocket_svc = make_service_fn(|_: _| {
-        let websocket_service = websocket_service;
-        async move { Ok::<_, Infallible>(websocket_service) }
-    });
+    let sass_reports = sass::compile_all(&app);
+    let report = build_wasm(&config).await;
+    report.log();
 
-    let websocket_srv = Server::bind(&([127, 0, 0, 1], 3000).into()).serve(make_websocket_svc);
+    match command {
+        Command::Build => {
+            if !report.is_success() || sass_reports.iter().any(|report| report.error.is_some()) {
+                process::exit(1);
+            }
+        }
+        Command::Watch => watch(config, app, ReloadChannel::new()).await,
+        Command::Serve => {
+            let reload = ReloadChannel::new();
+
+            for sass_report in sass_reports {
+                reload.send(sass_report.into());
+            }
+
+            reload.send(ReloadMessage::Build(report));
+            serve(config, app, reload).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn serve(
+    config: Config,
+    app: PathFilter,
+    reload: ReloadChannel,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let server_address = config.server_address()?;
+    let wasm_path = config.wasm_path.clone();
+
+    tokio::task::spawn(watch(config.clone(), app, reload.clone()));
+
+    let websocket_route = warp::path(RELOAD_PATH)
+        .and(warp::path::end())
+        .and(warp::ws())
+        .map(move |ws: warp::ws::Ws| {
+            let (errors, reload_rx) = reload.subscribe();
+            ws.on_upgrade(move |socket| connect(socket, errors, reload_rx))
+        });
+
+    let client_script = get_client_script(RELOAD_PATH);
+    let client_route = warp::path(RELOAD_PATH)
+        .and(warp::path(CLIENT_SCRIPT))
+        .and(warp::path::end())
+        .map(move || {
+            warp::reply::with_header(
+                client_script.clone(),
+                "content-type",
+                "application/javascript",
+            )
+        });
+    let client_url = format!("/{}/{}", RELOAD_PATH, CLIENT_SCRIPT);
+    let inject = config.inject_client;
 
     let index = warp::get().and(warp::fs::file(format!("{}/index.html", wasm_path)));
     let core = warp::path("pkg").and(warp::fs::dir(format!("{}/pkg", wasm_path)));
     let static_files = warp::path("static").and(warp::fs::dir(format!("{}/static", wasm_path)));
     let node_modules =
         warp::path("node_modules").and(warp::fs::dir(format!("{}/node_modules", wasm_path)));
-    let routes = core.or(node_modules.or(static_files.or(index)));
+    let files = core
+        .or(node_modules.or(static_files.or(index)))
+        .and_then(move |reply| inject_client(reply, client_url.clone(), inject));
+    let routes = websocket_route.or(client_route).or(files);
     let svc = warp::service(routes);
     let make_svc = make_service_fn(|_: _| {
         let svc = svc.clone();
@@ -61,97 +137,42 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let srv = Server::bind(&server_address).serve(make_svc);
     info!("stat server in address {}", server_address);
 
-    let _ret = join(websocket_srv, srv).await;
-
-    Ok(())
-}
-
-fn get_excluding_paths() -> Vec<String> {
-    if let Ok(env_excluding_paths) = env::var("EXCLUDING_PATHS") {
-        env_excluding_paths
-            .split(' ')
-            .map(String::from)
-            .collect::<Vec<String>>()
-    } else {
-        vec![
-            "target".to_string(),
-            "pkg".to_string(),
-            "static".to_string(),
-            "node_modules".to_string(),
-            "Cargo.lock".to_string(),
-        ]
-    }
-}
-
-fn get_env_build() -> String {
-    if let Ok(env_build) = env::var("ENV_BUILD") {
-        if env_build == "production" {
-            "--release".to_string()
-        } else {
-            "--dev".to_string()
-        }
-    } else {
-        "--dev".to_string()
-    }
-}
-
-fn get_wasm_path() -> String {
-    if let Ok(wasm_path) = env::var("WASM_PATH") {
-        wasm_path
-    } else {
-        "app".to_string()
-    }
-}
-
-fn get_server_address() -> String {
-    if let Ok(server_address) = env::var("SERVER_ADRESS") {
-        server_address
-    } else {
-        "127.0.0.1:8080".to_string()
-    }
-}
-
-async fn build_wasm() -> Result<(), Box<dyn std::error::Error>> {
-    let env_build = get_env_build();
-    let wasm_path = get_wasm_path();
-    let mut cmd = Command::new("wasm-pack");
-    let cmd = cmd
-        .arg("build")
-        .arg(env_build)
-        .args(&["--target", "web"])
-        .current_dir(wasm_path);
+    srv.await?;
 
-    cmd.stdout(Stdio::piped());
-
-    let mut child = cmd.spawn().expect("failed to spawn command");
-
-    let stdout = child
-        .stdout
-        .take()
-        .expect("child did not have a handle to stdout");
-
-    let mut reader = BufReader::new(stdout).lines();
-
-    while let Some(line) = reader.next_line().await? {
-        info!("{}", line);
-    }
     Ok(())
 }
 
-async fn connect(ws: WebSocket) {
+async fn connect(
+    ws: WebSocket,
+    errors: Vec<ReloadMessage>,
+    mut reload_rx: broadcast::Receiver<ReloadMessage>,
+) {
     let (mut browser_ws_tx, mut browser_ws_rx) = ws.split();
-    browser_ws_tx.send(Message::text("reload")).await.unwrap();
 
-    let (tx, rx) = mpsc::unbounded_channel();
-    let rx = UnboundedReceiverStream::new(rx);
-
-    tokio::task::spawn(rx.forward(browser_ws_tx).map(|result| {
-        if let Err(e) = result {
+    for message in std::iter::once(ReloadMessage::Connected).chain(errors) {
+        if let Err(e) = browser_ws_tx.send(message.to_message()).await {
             warn!("{}", e);
+            return;
         }
-    }));
+    }
 
-    tokio::task::spawn(watch(tx.clone()));
+    let forward = tokio::task::spawn(async move {
+        loop {
+            let msg = match reload_rx.recv().await {
+                Ok(msg) => msg,
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("websocket skipped {} messages", skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+
+            if let Err(e) = browser_ws_tx.send(msg.to_message()).await {
+                warn!("{}", e);
+                break;
+            }
+        }
+    });
 
     while let Some(result) = browser_ws_rx.next().await {
         let msg = match result {
@@ -167,58 +188,132 @@ async fn connect(ws: WebSocket) {
             Err(_e) => (),
         }
     }
+
+    forward.abort();
 }
 
-async fn watch(tx: mpsc::UnboundedSender<Result<Message, Error>>) {
-    let wasm_path = get_wasm_path();
-    let excluding_paths: Vec<String> = get_excluding_paths();
-    let mut process: Vec<AbortHandle> = vec![];
+/// Watch the directories of the config, where `app` filters the served directory of the app
+async fn watch(config: Config, app: PathFilter, reload: ReloadChannel) {
+    let mut filters = vec![];
+
+    for path in config.watch_paths() {
+        match PathFilter::new(Path::new(&path), &config) {
+            Ok(filter) => filters.push(filter),
+            Err(e) => error!("failed to watch {}", e),
+        }
+    }
+
+    let mut watcher = match SourceWatcher::new(filters, config.debounce()) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            error!("failed to watch: {}", e);
+            return;
+        }
+    };
+    let mut pending = None;
+
     loop {
-        let tx = tx.clone();
-        let wasm_path = wasm_path.clone();
-        match fs::read_dir(".diff").await {
-            Ok(_file) => (),
-            Err(_e) => {
-                Command::new("cp")
-                    .arg("-R")
-                    .args(&[wasm_path.clone(), ".diff".to_string()])
-                    .output()
-                    .await
-                    .unwrap();
+        let sources = match pending.take() {
+            Some(sources) => sources,
+            None => match watcher.changes().await {
+                Some(changes) => hot_swap(&reload, &app, changes),
+                None => break,
+            },
+        };
+
+        if sources.is_empty() {
+            continue;
+        }
+
+        let current_dir = env::current_dir().unwrap_or_default();
+        let paths = sources
+            .iter()
+            .map(|path| {
+                let path = path.strip_prefix(&current_dir).unwrap_or(path);
+                path.display().to_string()
+            })
+            .collect::<Vec<String>>();
+        info!("sources change: {}", paths.join(", "));
+
+        let build = build_wasm(&config);
+        tokio::pin!(build);
+
+        loop {
+            // newer source changes drop the running build, which kills it, and start another one
+            tokio::select! {
+                report = &mut build => {
+                    report.log();
+                    reload.send(ReloadMessage::Build(report));
+                    break;
+                }
+                Some(changes) = watcher.changes() => {
+                    let sources = hot_swap(&reload, &app, changes);
+
+                    if !sources.is_empty() {
+                        info!("sources change during the build, restarting it");
+                        pending = Some(sources);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Compile the Sass files and send the changes of the stylesheets, pages and
+/// assets to the browser tabs, returning the changed sources which need a build.
+/// Out of the served directory of the app only the sources are taken into account
+fn hot_swap(
+    reload: &ReloadChannel,
+    app: &PathFilter,
+    changes: BTreeSet<PathBuf>,
+) -> BTreeSet<PathBuf> {
+    let mut sources = BTreeSet::new();
+    let mut sass_change = false;
+
+    for path in changes {
+        let relative = match path.strip_prefix(app.root()) {
+            Ok(relative) => relative.to_path_buf(),
+            Err(_) => {
+                if change_kind(&path) == ChangeKind::Source {
+                    sources.insert(path);
+                }
+                continue;
             }
         };
+        let url = get_url(&relative);
 
-        let diff = dir_diff(DirDiff {
-            dir: wasm_path.clone(),
-            dir_comp: ".diff".to_string(),
-            excluding: Some(excluding_paths.clone()),
-            recursive_excluding: true,
-        })
-        .await;
-
-        if diff {
-            for p in process.clone() {
-                p.abort();
-                process.pop();
+        match change_kind(&relative) {
+            ChangeKind::Source => {
+                sources.insert(path);
             }
+            ChangeKind::Sass => sass_change = true,
+            // the compiled stylesheets are swapped when their Sass files are compiled
+            ChangeKind::Stylesheet if sass::is_compiled(&path) => (),
+            ChangeKind::Stylesheet => reload.send(ReloadMessage::CssUpdate { path: url }),
+            ChangeKind::Page => reload.send(ReloadMessage::Reload),
+            ChangeKind::Asset => reload.send(ReloadMessage::AssetUpdate { path: url }),
+            ChangeKind::Other => (),
+        }
+    }
 
-            let tx = tx.clone();
-            let (fut, handle) = abortable(async move {
-                info!("sources change");
-                build_wasm().await.unwrap();
-                tx.send(Ok(Message::text("reload"))).unwrap();
-                Command::new("rm")
-                    .arg("-R")
-                    .arg(".diff")
-                    .output()
-                    .await
-                    .unwrap();
-            });
-
-            process.push(handle);
-
-            fut.await.unwrap();
+    // the partials can be imported by any Sass file, so all of them are compiled again.
+    // The compiled stylesheets are swapped right away, since they are usually ignored
+    // and the watcher doesn't report them
+    if sass_change {
+        for report in sass::compile_all(app) {
+            let stylesheet = match report.error {
+                None => Some(get_url(&report.path.with_extension("css"))),
+                Some(_) => None,
+            };
+
+            reload.send(report.into());
+
+            if let Some(path) = stylesheet {
+                reload.send(ReloadMessage::CssUpdate { path });
+            }
         }
-        sleep(Duration::from_millis(1000)).await;
     }
+
+    sources
 }
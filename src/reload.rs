@@ -0,0 +1,122 @@
+use crate::build::BuildReport;
+use crate::sass::SassReport;
+use crate::watcher::get_url;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+use warp::ws::Message;
+
+/// Messages kept for the browser tabs which are slower to receive them
+const CAPACITY: usize = 16;
+
+/// Messages sent to the browser tabs as json, tagged by `type`
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum ReloadMessage {
+    /// First message of every connection
+    Connected,
+    /// Result of a build, the tabs reload when it succeeds
+    Build(BuildReport),
+    /// Reload the page without a build
+    Reload,
+    /// Result of the compilation of a Sass file, the stylesheet is swapped when it succeeds
+    Sass { path: String, error: Option<String> },
+    /// Swap the stylesheets which link the path
+    CssUpdate { path: String },
+    /// Refresh the elements which load the path, like the images
+    AssetUpdate { path: String },
+}
+
+impl ReloadMessage {
+    pub fn to_message(&self) -> Message {
+        Message::text(serde_json::to_string(self).expect("reload message to json"))
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            ReloadMessage::Connected => "connected",
+            ReloadMessage::Build(_) => "build",
+            ReloadMessage::Reload => "reload",
+            ReloadMessage::Sass { .. } => "sass",
+            ReloadMessage::CssUpdate { .. } => "css-update",
+            ReloadMessage::AssetUpdate { .. } => "asset-update",
+        }
+    }
+}
+
+impl From<SassReport> for ReloadMessage {
+    fn from(report: SassReport) -> Self {
+        ReloadMessage::Sass {
+            path: get_url(&report.path),
+            error: report.error,
+        }
+    }
+}
+
+/// Broadcast of the reload messages to all the browser tabs, which keeps the
+/// last build and the Sass errors to show them to the tabs opened after them
+#[derive(Clone)]
+pub struct ReloadChannel {
+    tx: broadcast::Sender<ReloadMessage>,
+    last_build: Arc<Mutex<Option<BuildReport>>>,
+    /// Errors of the Sass files by url
+    sass_errors: Arc<Mutex<BTreeMap<String, String>>>,
+}
+
+impl ReloadChannel {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(CAPACITY);
+
+        Self {
+            tx,
+            last_build: Arc::new(Mutex::new(None)),
+            sass_errors: Arc::new(Mutex::new(BTreeMap::new())),
+        }
+    }
+
+    /// Receiver of the next messages, with the messages of the current errors
+    /// so the tabs opened while the build or a Sass file is broken show them too
+    pub fn subscribe(&self) -> (Vec<ReloadMessage>, broadcast::Receiver<ReloadMessage>) {
+        let mut errors = vec![];
+
+        if let Some(report) = self.last_build.lock().unwrap().clone() {
+            if !report.is_success() {
+                errors.push(ReloadMessage::Build(report));
+            }
+        }
+
+        for (path, error) in self.sass_errors.lock().unwrap().iter() {
+            errors.push(ReloadMessage::Sass {
+                path: path.clone(),
+                error: Some(error.clone()),
+            });
+        }
+
+        (errors, self.tx.subscribe())
+    }
+
+    pub fn send(&self, message: ReloadMessage) {
+        match &message {
+            ReloadMessage::Build(report) => {
+                *self.last_build.lock().unwrap() = Some(report.clone());
+            }
+            ReloadMessage::Sass { path, error } => {
+                let mut sass_errors = self.sass_errors.lock().unwrap();
+
+                match error {
+                    Some(error) => sass_errors.insert(path.clone(), error.clone()),
+                    None => sass_errors.remove(path),
+                };
+            }
+            _ => (),
+        }
+
+        info!(
+            "send {} to {} browser tabs",
+            message.kind(),
+            self.tx.receiver_count()
+        );
+        self.tx.send(message).ok();
+    }
+}
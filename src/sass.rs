@@ -0,0 +1,79 @@
+use crate::filter::PathFilter;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Result of the compilation of a Sass file into the css file next to it
+pub struct SassReport {
+    /// Path of the Sass file relative to the root
+    pub path: PathBuf,
+    pub error: Option<String>,
+}
+
+pub fn is_sass(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|extension| extension.to_str()),
+        Some("sass") | Some("scss")
+    )
+}
+
+/// Whether a stylesheet is compiled from a Sass file next to it
+pub fn is_compiled(css: &Path) -> bool {
+    ["sass", "scss"]
+        .iter()
+        .any(|extension| css.with_extension(extension).is_file())
+}
+
+/// Compile all the watched Sass files under the root of the filter, but the partials
+/// starting with `_` which are only compiled as part of the files which import them
+pub fn compile_all(filter: &PathFilter) -> Vec<SassReport> {
+    let root = filter.root();
+    let mut files = vec![];
+    find_sass_files(root, filter, &mut files);
+
+    files
+        .into_iter()
+        .map(|file| {
+            let error = compile(&file).err();
+            let path = file.strip_prefix(root).unwrap_or(&file).to_path_buf();
+
+            match &error {
+                Some(error) => error!("sass {}: {}", path.display(), error),
+                None => info!("sass {} compiled", path.display()),
+            }
+
+            SassReport { path, error }
+        })
+        .collect()
+}
+
+fn compile(file: &Path) -> Result<(), String> {
+    let css = grass::from_path(file, &grass::Options::default()).map_err(|e| e.to_string())?;
+
+    fs::write(file.with_extension("css"), css).map_err(|e| e.to_string())
+}
+
+fn find_sass_files(dir: &Path, filter: &PathFilter, files: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            warn!("sass {}: {}", dir.display(), e);
+            return;
+        }
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name();
+        let is_dir = path.is_dir();
+
+        if !filter.is_watched(&path, is_dir) {
+            continue;
+        }
+
+        if is_dir {
+            find_sass_files(&path, filter, files);
+        } else if is_sass(&path) && !name.to_string_lossy().starts_with('_') {
+            files.push(path);
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use crate::filter::{is_ignore_file, PathFilter};
+use notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Extensions of the files which are served as they are, without building them
+const ASSET_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "ico", "woff", "woff2", "ttf", "otf", "map",
+];
+
+/// How a changed file gets to the browser
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Rust sources and manifests, which need a wasm-pack build
+    Source,
+    /// Sass files, compiled into the stylesheets next to them
+    Sass,
+    /// Stylesheets, swapped in place
+    Stylesheet,
+    /// Html pages and scripts, which reload the page without a build
+    Page,
+    /// Images, fonts and the rest of the static files, refreshed in place
+    Asset,
+    /// Files which don't get to the browser, like the readme or `package.json`
+    Other,
+}
+
+pub fn change_kind(path: &Path) -> ChangeKind {
+    if path.file_name().is_some_and(|name| name == "Cargo.lock") {
+        return ChangeKind::Source;
+    }
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("sass") | Some("scss") => ChangeKind::Sass,
+        Some("css") => ChangeKind::Stylesheet,
+        Some("html") => ChangeKind::Page,
+        Some("rs") | Some("toml") => ChangeKind::Source,
+        _ if path.starts_with("static") => ChangeKind::Asset,
+        Some(extension) if ASSET_EXTENSIONS.contains(&extension) => ChangeKind::Asset,
+        Some("js") | Some("mjs") => ChangeKind::Page,
+        _ => ChangeKind::Other,
+    }
+}
+
+/// Url of a path relative to the served directory
+pub fn get_url(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+/// Watch the sources of some directories, reporting the absolute paths of the
+/// changes once the events stop arriving during the debounce time.
+/// Only the directories which aren't ignored are watched, so the big ignored ones
+/// like `target` or `node_modules` don't use watches nor send events
+pub struct SourceWatcher {
+    debouncer: Debouncer<RecommendedWatcher>,
+    filters: Vec<PathFilter>,
+    rx: mpsc::UnboundedReceiver<Vec<PathBuf>>,
+}
+
+impl SourceWatcher {
+    pub fn new(filters: Vec<PathFilter>, debounce: Duration) -> notify::Result<Self> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let debouncer = new_debouncer(debounce, move |result: DebounceEventResult| match result {
+            Ok(events) => {
+                tx.send(events.into_iter().map(|event| event.path).collect())
+                    .ok();
+            }
+            Err(e) => warn!("watch {}", e),
+        })?;
+
+        let mut watcher = Self {
+            debouncer,
+            filters,
+            rx,
+        };
+
+        for root in watcher.roots() {
+            watcher.watch_dir(&root)?;
+        }
+
+        Ok(watcher)
+    }
+
+    /// Wait the next changed paths, coalesced with the changes which arrived meanwhile
+    pub async fn changes(&mut self) -> Option<BTreeSet<PathBuf>> {
+        loop {
+            let mut events = self.rx.recv().await?;
+
+            while let Ok(more) = self.rx.try_recv() {
+                events.extend(more);
+            }
+
+            let paths = self.get_changes(events);
+
+            if !paths.is_empty() {
+                return Some(paths);
+            }
+        }
+    }
+
+    /// Watched files of the events, watching the new directories
+    fn get_changes(&mut self, events: Vec<PathBuf>) -> BTreeSet<PathBuf> {
+        // the ignore files only change which paths are reported, they aren't sources
+        if events.iter().any(|path| is_ignore_file(path)) {
+            self.filters
+                .iter_mut()
+                .for_each(PathFilter::load_ignore_files);
+
+            for root in self.roots() {
+                if let Err(e) = self.watch_dir(&root) {
+                    warn!("watch {}", e);
+                }
+            }
+        }
+
+        let mut paths = BTreeSet::new();
+
+        for path in events {
+            if is_ignore_file(&path) || !is_watched(&self.filters, &path) {
+                continue;
+            }
+
+            if path.is_dir() {
+                // the files created with the directory, before it was watched, are changes too
+                match self.watch_dir(&path) {
+                    Ok(files) => paths.extend(files),
+                    Err(e) => warn!("watch {}: {}", path.display(), e),
+                }
+            } else {
+                paths.insert(path);
+            }
+        }
+
+        paths
+    }
+
+    /// Watch a directory and the directories under it which aren't ignored,
+    /// returning the watched files found in them
+    fn watch_dir(&mut self, dir: &Path) -> notify::Result<Vec<PathBuf>> {
+        let mut files = vec![];
+        let mut dirs = vec![dir.to_path_buf()];
+
+        while let Some(dir) = dirs.pop() {
+            self.debouncer
+                .watcher()
+                .watch(&dir, RecursiveMode::NonRecursive)?;
+
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let is_dir = path.is_dir();
+
+                if !is_watched(&self.filters, &path) {
+                    continue;
+                }
+
+                if is_dir {
+                    dirs.push(path);
+                } else if !is_ignore_file(&path) {
+                    files.push(path);
+                }
+            }
+        }
+
+        Ok(files)
+    }
+
+    fn roots(&self) -> Vec<PathBuf> {
+        self.filters
+            .iter()
+            .map(|filter| filter.root().to_path_buf())
+            .collect()
+    }
+}
+
+/// Whether the path is watched by the filter of its innermost root, when the roots are nested
+fn is_watched(filters: &[PathFilter], path: &Path) -> bool {
+    filters
+        .iter()
+        .filter(|filter| path.starts_with(filter.root()))
+        .max_by_key(|filter| filter.root().components().count())
+        .is_some_and(|filter| filter.is_watched(path, path.is_dir()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::filter::tests::{temp_root, write};
+    use tokio::time::timeout;
+
+    #[test]
+    fn should_only_build_the_rust_sources() {
+        for (path, kind) in [
+            ("src/main.rs", ChangeKind::Source),
+            ("Cargo.toml", ChangeKind::Source),
+            ("Cargo.lock", ChangeKind::Source),
+            ("static/main.sass", ChangeKind::Sass),
+            ("static/main.css", ChangeKind::Stylesheet),
+            ("index.html", ChangeKind::Page),
+            ("app.js", ChangeKind::Page),
+            ("static/logo.png", ChangeKind::Asset),
+            ("static/data.json", ChangeKind::Asset),
+            ("favicon.ico", ChangeKind::Asset),
+            ("package.json", ChangeKind::Other),
+            ("README.md", ChangeKind::Other),
+            (".env", ChangeKind::Other),
+            ("LICENSE", ChangeKind::Other),
+        ]
+        .iter()
+        {
+            assert_eq!(change_kind(Path::new(path)), *kind, "{}", path);
+        }
+    }
+
+    #[test]
+    fn should_use_the_filter_of_the_innermost_root() {
+        let root = temp_root("watcher_roots");
+        write(&root, "index.html", "");
+        write(&root, "lib/src/lib.rs", "");
+        write(&root, "lib/README.md", "");
+
+        let outer = Config {
+            include: vec!["*.html".to_string()],
+            ..Config::default()
+        };
+        let inner = Config {
+            include: vec!["src/**/*.rs".to_string()],
+            ..Config::default()
+        };
+        // the inner root first, to not depend on the order of the roots
+        let filters = vec![
+            PathFilter::new(&root.join("lib"), &inner).unwrap(),
+            PathFilter::new(&root, &outer).unwrap(),
+        ];
+        let root = filters[1].root().to_path_buf();
+
+        assert!(is_watched(&filters, &root.join("index.html")));
+        assert!(is_watched(&filters, &root.join("lib/src/lib.rs")));
+        assert!(!is_watched(&filters, &root.join("lib/README.md")));
+        assert!(!is_watched(&filters, Path::new("/elsewhere/index.html")));
+    }
+
+    async fn next_changes(watcher: &mut SourceWatcher) -> BTreeSet<PathBuf> {
+        timeout(Duration::from_secs(5), watcher.changes())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_watch_the_new_directories_which_are_not_ignored() {
+        let root = temp_root("watcher_new_dirs");
+        write(&root, "src/main.rs", "");
+        write(&root, "target/debug/main.rs", "");
+
+        let config = Config {
+            ignore: vec!["target".to_string()],
+            ..Config::default()
+        };
+        let filter = PathFilter::new(&root, &config).unwrap();
+        let root = filter.root().to_path_buf();
+        let mut watcher = SourceWatcher::new(vec![filter], Duration::from_millis(50)).unwrap();
+
+        write(&root, "target/debug/main.rs", "fn main() {}");
+        write(&root, "src/module/mod.rs", "");
+        let paths = next_changes(&mut watcher).await;
+
+        assert!(paths.contains(&root.join("src/module/mod.rs")));
+        assert!(!paths
+            .iter()
+            .any(|path| path.starts_with(root.join("target"))));
+
+        write(&root, "src/module/lib.rs", "");
+        let paths = next_changes(&mut watcher).await;
+
+        assert!(paths.contains(&root.join("src/module/lib.rs")));
+    }
+}